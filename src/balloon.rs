@@ -0,0 +1,128 @@
+//! Traditional Memory Balloon Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::{ReadOnly, ReadWrite};
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::balloon::F;
+use crate::{le16, le32, le64};
+
+/// Traditional Memory Balloon Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_balloon_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The number of pages the device wants the balloon to contain.
+    #[access(ReadOnly)]
+    num_pages: le32,
+
+    /// The number of pages the driver has placed in the balloon.
+    #[access(ReadWrite)]
+    actual: le32,
+
+    /// The command ID of the current free page hinting request.
+    ///
+    /// Only if [`VIRTIO_BALLOON_F_FREE_PAGE_HINT`](F::FREE_PAGE_HINT) negotiated.
+    /// See also [`CMD_ID_STOP`] and [`CMD_ID_DONE`].
+    #[access(ReadOnly)]
+    free_page_hint_cmd_id: le32,
+
+    /// The value the driver fills reported free pages with.
+    ///
+    /// Only if [`VIRTIO_BALLOON_F_PAGE_POISON`](F::PAGE_POISON) negotiated.
+    #[access(ReadWrite)]
+    poison_val: le32,
+}
+
+/// The shift of page frame numbers in the inflateq and the deflateq.
+///
+/// Page frame numbers always refer to 4096-byte pages, independent of the guest page size.
+#[doc(alias = "VIRTIO_BALLOON_PFN_SHIFT")]
+pub const PFN_SHIFT: u32 = 12;
+
+/// Free page hinting command ID to stop hinting.
+#[doc(alias = "VIRTIO_BALLOON_CMD_ID_STOP")]
+pub const CMD_ID_STOP: u32 = 0;
+
+/// Free page hinting command ID to signal that hinting is done.
+#[doc(alias = "VIRTIO_BALLOON_CMD_ID_DONE")]
+pub const CMD_ID_DONE: u32 = 1;
+
+/// Memory Statistic
+///
+/// Only if [`VIRTIO_BALLOON_F_STATS_VQ`](F::STATS_VQ) negotiated.
+/// The driver reports memory statistics as an array of these entries on the statsq.
+#[doc(alias = "virtio_balloon_stat")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct Stat {
+    /// See [`StatTag`].
+    pub tag: le16,
+    pub val: le64,
+}
+
+/// Memory Statistic Tag
+#[doc(alias = "VIRTIO_BALLOON_S")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum StatTag {
+    /// The amount of memory that has been swapped in (in bytes).
+    #[doc(alias = "VIRTIO_BALLOON_S_SWAP_IN")]
+    SwapIn = 0,
+
+    /// The amount of memory that has been swapped out to disk (in bytes).
+    #[doc(alias = "VIRTIO_BALLOON_S_SWAP_OUT")]
+    SwapOut = 1,
+
+    /// The number of major page faults that have occurred.
+    #[doc(alias = "VIRTIO_BALLOON_S_MAJFLT")]
+    Majflt = 2,
+
+    /// The number of minor page faults that have occurred.
+    #[doc(alias = "VIRTIO_BALLOON_S_MINFLT")]
+    Minflt = 3,
+
+    /// The amount of memory not being used for any purpose (in bytes).
+    #[doc(alias = "VIRTIO_BALLOON_S_MEMFREE")]
+    Memfree = 4,
+
+    /// The total amount of memory available (in bytes).
+    #[doc(alias = "VIRTIO_BALLOON_S_MEMTOT")]
+    Memtot = 5,
+
+    /// An estimate of how much memory is available (in bytes) for starting new applications, without pushing the system to swap.
+    #[doc(alias = "VIRTIO_BALLOON_S_AVAIL")]
+    Avail = 6,
+
+    /// The amount of memory, in bytes, that can be quickly reclaimed without additional I/O.
+    #[doc(alias = "VIRTIO_BALLOON_S_CACHES")]
+    Caches = 7,
+
+    /// The number of successful hugetlb page allocations in the guest.
+    #[doc(alias = "VIRTIO_BALLOON_S_HTLB_PGALLOC")]
+    HtlbPgalloc = 8,
+
+    /// The number of failed hugetlb page allocations in the guest.
+    #[doc(alias = "VIRTIO_BALLOON_S_HTLB_PGFAIL")]
+    HtlbPgfail = 9,
+}
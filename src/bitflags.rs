@@ -39,6 +39,68 @@ macro_rules! _bitflags_base {
             }
         }
 
+        impl ::core::fmt::Display for $BitFlags {
+            /// Writes the names of the set flags separated by ` | `.
+            ///
+            /// Unnamed bits are written as a trailing hex number.
+            /// An empty flags value is written as `0x0`.
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                if self.is_empty() {
+                    f.write_str("0x0")
+                } else {
+                    ::bitflags::parser::to_writer(self, f)
+                }
+            }
+        }
+
+        impl crate::bytes::Field for $BitFlags {
+            fn read(bytes: &[u8]) -> Self {
+                Self(crate::bytes::Field::read(bytes))
+            }
+
+            fn write(&self, bytes: &mut [u8]) {
+                crate::bytes::Field::write(&self.0, bytes);
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $BitFlags {
+            /// Serializes the bits as a native-endian number.
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                crate::endian_serde::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $BitFlags {
+            /// Deserializes the bits from a native-endian number, retaining unknown bits.
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                crate::endian_serde::deserialize(deserializer).map(Self)
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl ::defmt::Format for $BitFlags {
+            /// Formats the bits as a native-endian hex number.
+            fn format(&self, f: ::defmt::Formatter<'_>) {
+                ::defmt::write!(
+                    f,
+                    "{=str}({:#x})",
+                    ::core::stringify!($BitFlags),
+                    crate::endian_defmt::Field::value(self.0),
+                );
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl crate::endian_defmt::Field for $BitFlags {
+            type Value = Self;
+
+            fn value(self) -> Self::Value {
+                self
+            }
+        }
+
         _bitflags_base! {
             $($t)*
         }
@@ -74,6 +136,19 @@ macro_rules! virtio_bitflags {
             }
         }
 
+        impl $BitFlags {
+            /// Unset all bits that do not correspond to a named flag.
+            ///
+            /// All bits are considered known, so [`from_bits_retain`](Self::from_bits_retain)
+            /// and [`from_bits_truncate`](Self::from_bits_truncate) preserve bits that are
+            /// reserved or newer than this crate. Use this method to drop them explicitly.
+            #[inline]
+            #[must_use]
+            pub const fn truncate(self) -> Self {
+                Self::from_bits_retain(self.bits() & (0 $(| $value)*))
+            }
+        }
+
         virtio_bitflags! {
             $($t)*
         }
@@ -276,6 +351,17 @@ macro_rules! endian_bitflags {
             pub const fn complement(self) -> Self {
                 Self::from_bits_truncate(<$T>::from_ne(!self.bits().to_ne()))
             }
+
+            /// Unset all bits that do not correspond to a named flag.
+            ///
+            /// All bits are considered known, so [`from_bits_retain`](Self::from_bits_retain)
+            /// and [`from_bits_truncate`](Self::from_bits_truncate) preserve bits that are
+            /// reserved or newer than this crate. Use this method to drop them explicitly.
+            #[inline]
+            #[must_use]
+            pub const fn truncate(self) -> Self {
+                Self::from_bits_retain(<$T>::from_ne(self.bits().to_ne() & (0 $(| $value)*)))
+            }
         }
 
         impl_fmt!(Binary for $BitFlags);
@@ -431,3 +517,40 @@ macro_rules! endian_bitflags {
     };
     () => {};
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::virtq::DescF;
+    use crate::DeviceStatus;
+
+    #[test]
+    fn truncate() {
+        let status = DeviceStatus::from_bits_retain(0x10 | 0x01);
+        assert_eq!(status.bits(), 0x11);
+        assert_eq!(status.truncate(), DeviceStatus::ACKNOWLEDGE);
+
+        let flags = DescF::from_bits_retain((0x100 | 0x1).into());
+        assert_eq!(flags.bits().to_ne(), 0x101);
+        assert_eq!(flags.truncate(), DescF::NEXT);
+    }
+
+    #[test]
+    fn display() {
+        use std::string::ToString;
+
+        let status = DeviceStatus::ACKNOWLEDGE | DeviceStatus::DRIVER;
+        assert_eq!(status.to_string(), "ACKNOWLEDGE | DRIVER");
+        assert_eq!(
+            status.iter_names().collect::<std::vec::Vec<_>>(),
+            [
+                ("ACKNOWLEDGE", DeviceStatus::ACKNOWLEDGE),
+                ("DRIVER", DeviceStatus::DRIVER)
+            ]
+        );
+        assert_eq!(DeviceStatus::empty().to_string(), "0x0");
+
+        let flags = DescF::NEXT | DescF::WRITE;
+        assert_eq!(flags.to_string(), "NEXT | WRITE");
+        assert_eq!(DescF::from_bits_retain(0x100.into()).to_string(), "0x100");
+    }
+}
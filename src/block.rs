@@ -0,0 +1,691 @@
+//! Block Device
+
+use core::mem;
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::{ReadOnly, ReadWrite};
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::block::F;
+use crate::{le16, le32, le64};
+
+/// Block Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_blk_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Config {
+    /// The capacity of the device (expressed in 512-byte sectors).
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    capacity: le64,
+
+    /// Maximum size of any single segment.
+    ///
+    /// Only if [`VIRTIO_BLK_F_SIZE_MAX`](F::SIZE_MAX) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    size_max: le32,
+
+    /// Maximum number of segments in a request.
+    ///
+    /// Only if [`VIRTIO_BLK_F_SEG_MAX`](F::SEG_MAX) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    seg_max: le32,
+
+    /// Disk-style geometry.
+    ///
+    /// Only if [`VIRTIO_BLK_F_GEOMETRY`](F::GEOMETRY) negotiated.
+    #[access(ReadOnly)]
+    geometry: Geometry,
+
+    /// Block size of disk.
+    ///
+    /// Only if [`VIRTIO_BLK_F_BLK_SIZE`](F::BLK_SIZE) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    blk_size: le32,
+
+    /// Information on optimal I/O alignment.
+    ///
+    /// Only if [`VIRTIO_BLK_F_TOPOLOGY`](F::TOPOLOGY) negotiated.
+    #[access(ReadOnly)]
+    topology: Topology,
+
+    /// Cache mode: 0 for writethrough and 1 for writeback.
+    ///
+    /// Writable only if [`VIRTIO_BLK_F_CONFIG_WCE`](F::CONFIG_WCE) negotiated.
+    #[access(ReadWrite)]
+    writeback: u8,
+
+    #[access(ReadOnly)]
+    unused0: u8,
+
+    /// Number of virtqueues.
+    ///
+    /// Only if [`VIRTIO_BLK_F_MQ`](F::MQ) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    num_queues: le16,
+
+    /// Maximum discard sectors for one segment.
+    ///
+    /// Only if [`VIRTIO_BLK_F_DISCARD`](F::DISCARD) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    max_discard_sectors: le32,
+
+    /// Maximum number of discard segments in a discard command.
+    ///
+    /// Only if [`VIRTIO_BLK_F_DISCARD`](F::DISCARD) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    max_discard_seg: le32,
+
+    /// Alignment of the discard sectors, in 512-byte sectors.
+    ///
+    /// Only if [`VIRTIO_BLK_F_DISCARD`](F::DISCARD) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    discard_sector_alignment: le32,
+
+    /// Maximum write zeroes sectors for one segment.
+    ///
+    /// Only if [`VIRTIO_BLK_F_WRITE_ZEROES`](F::WRITE_ZEROES) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    max_write_zeroes_sectors: le32,
+
+    /// Maximum number of write zeroes segments in a write zeroes command.
+    ///
+    /// Only if [`VIRTIO_BLK_F_WRITE_ZEROES`](F::WRITE_ZEROES) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    max_write_zeroes_seg: le32,
+
+    /// Set to 1 if the device may unmap sectors on write zeroes commands.
+    ///
+    /// Only if [`VIRTIO_BLK_F_WRITE_ZEROES`](F::WRITE_ZEROES) negotiated.
+    #[access(ReadOnly)]
+    write_zeroes_may_unmap: u8,
+
+    #[access(ReadOnly)]
+    unused1: [u8; 3],
+
+    /// Maximum secure erase sectors for one segment.
+    ///
+    /// Only if [`VIRTIO_BLK_F_SECURE_ERASE`](F::SECURE_ERASE) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    max_secure_erase_sectors: le32,
+
+    /// Maximum number of secure erase segments in a secure erase command.
+    ///
+    /// Only if [`VIRTIO_BLK_F_SECURE_ERASE`](F::SECURE_ERASE) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    max_secure_erase_seg: le32,
+
+    /// Alignment of the secure erase sectors, in 512-byte sectors.
+    ///
+    /// Only if [`VIRTIO_BLK_F_SECURE_ERASE`](F::SECURE_ERASE) negotiated.
+    #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    secure_erase_sector_alignment: le32,
+
+    /// Zoned block device characteristics.
+    ///
+    /// Only if [`VIRTIO_BLK_F_ZONED`](F::ZONED) negotiated.
+    #[access(ReadOnly)]
+    zoned: ZonedCharacteristics,
+}
+
+impl Config {
+    /// Returns the minimum length of the device configuration for the negotiated `features`.
+    ///
+    /// The transport has to provide at least this many bytes of device-specific configuration
+    /// for all fields that are present with `features` to be accessible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::block::{Config, F};
+    ///
+    /// assert_eq!(Config::min_len(F::empty()), 8);
+    /// assert_eq!(Config::min_len(F::SEG_MAX | F::BLK_SIZE), 24);
+    /// ```
+    pub fn min_len(features: F) -> usize {
+        [
+            (
+                F::SIZE_MAX,
+                mem::offset_of!(Self, size_max) + mem::size_of::<le32>(),
+            ),
+            (
+                F::SEG_MAX,
+                mem::offset_of!(Self, seg_max) + mem::size_of::<le32>(),
+            ),
+            (
+                F::GEOMETRY,
+                mem::offset_of!(Self, geometry) + mem::size_of::<Geometry>(),
+            ),
+            (
+                F::BLK_SIZE,
+                mem::offset_of!(Self, blk_size) + mem::size_of::<le32>(),
+            ),
+            (
+                F::TOPOLOGY,
+                mem::offset_of!(Self, topology) + mem::size_of::<Topology>(),
+            ),
+            (
+                F::CONFIG_WCE,
+                mem::offset_of!(Self, writeback) + mem::size_of::<u8>(),
+            ),
+            (
+                F::MQ,
+                mem::offset_of!(Self, num_queues) + mem::size_of::<le16>(),
+            ),
+            (
+                F::DISCARD,
+                mem::offset_of!(Self, discard_sector_alignment) + mem::size_of::<le32>(),
+            ),
+            (
+                F::WRITE_ZEROES,
+                mem::offset_of!(Self, write_zeroes_may_unmap) + mem::size_of::<u8>(),
+            ),
+            (
+                F::SECURE_ERASE,
+                mem::offset_of!(Self, secure_erase_sector_alignment) + mem::size_of::<le32>(),
+            ),
+            (
+                F::ZONED,
+                mem::offset_of!(Self, zoned) + mem::size_of::<ZonedCharacteristics>(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(feature, _)| features.intersects(*feature))
+        .map(|(_, len)| len)
+        .fold(mem::size_of::<le64>(), usize::max)
+    }
+}
+
+/// Block Device Geometry
+#[doc(alias = "virtio_blk_geometry")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Geometry {
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub cylinders: le16,
+    pub heads: u8,
+    pub sectors: u8,
+}
+
+defmt_format_impl! {
+    Geometry {
+        cylinders,
+        heads,
+        sectors,
+    }
+}
+
+/// Block Device Topology
+#[doc(alias = "virtio_blk_topology")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Topology {
+    /// Number of logical blocks per physical block (log2)
+    pub physical_block_exp: u8,
+
+    /// Offset of first aligned logical block
+    pub alignment_offset: u8,
+
+    /// Suggested minimum I/O size in blocks
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub min_io_size: le16,
+
+    /// Optimal (suggested maximum) I/O size in blocks
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub opt_io_size: le32,
+}
+
+defmt_format_impl! {
+    Topology {
+        physical_block_exp,
+        alignment_offset,
+        min_io_size,
+        opt_io_size,
+    }
+}
+
+/// Zoned Block Device Characteristics
+#[doc(alias = "virtio_blk_zoned_characteristics")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct ZonedCharacteristics {
+    /// The size of a zone in 512-byte sectors.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub zone_sectors: le32,
+
+    /// The maximum number of zones that can be open on the device at the same time.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub max_open_zones: le32,
+
+    /// The maximum number of zones that can be active on the device at the same time.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub max_active_zones: le32,
+
+    /// The maximum data size of a [`VIRTIO_BLK_T_ZONE_APPEND`](T::ZoneAppend) request in 512-byte sectors.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub max_append_sectors: le32,
+
+    /// The offset and size alignment constraint for write requests in bytes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
+    pub write_granularity: le32,
+
+    /// The zoned device model, see [`Model`].
+    pub model: u8,
+
+    pub unused2: [u8; 3],
+}
+
+defmt_format_impl! {
+    ZonedCharacteristics {
+        zone_sectors,
+        max_open_zones,
+        max_active_zones,
+        max_append_sectors,
+        write_granularity,
+        model,
+        unused2,
+    }
+}
+
+/// Zoned Device Model
+#[doc(alias = "VIRTIO_BLK_Z")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Model {
+    /// The device is not zoned.
+    #[doc(alias = "VIRTIO_BLK_Z_NONE")]
+    None = 0,
+
+    /// Host-managed zoned device
+    #[doc(alias = "VIRTIO_BLK_Z_HM")]
+    Hm = 1,
+
+    /// Host-aware zoned device
+    #[doc(alias = "VIRTIO_BLK_Z_HA")]
+    Ha = 2,
+}
+
+/// Block Device Request Header
+///
+/// This is the device-readable part of `virtio_blk_req`.
+/// The data buffer and the device-writable `status` byte follow in separate descriptors.
+#[doc(alias = "virtio_blk_req")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Req {
+    /// The request type, see [`T`].
+    pub type_: le32,
+    pub reserved: le32,
+    /// The offset (multiplied by 512) where the read or write is to occur.
+    pub sector: le64,
+}
+
+defmt_format_impl! {
+    Req {
+        type_,
+        reserved,
+        sector,
+    }
+}
+
+impl Req {
+    /// Creates a new request header.
+    ///
+    /// `sector` is only used by [`VIRTIO_BLK_T_IN`](T::In) and
+    /// [`VIRTIO_BLK_T_OUT`](T::Out) and should be 0 otherwise.
+    ///
+    /// The request header is followed by the data buffers and a trailing status byte (see [`S`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::block::{Req, T};
+    ///
+    /// let req = Req::new(T::Out, 8);
+    /// assert_eq!(req.type_.to_ne(), 1);
+    /// assert_eq!(req.sector.to_ne(), 8);
+    /// ```
+    pub const fn new(type_: T, sector: u64) -> Self {
+        Self {
+            type_: le32::from_ne(type_ as u32),
+            reserved: le32::from_ne(0),
+            sector: le64::from_ne(sector),
+        }
+    }
+}
+
+/// Request Type
+#[doc(alias = "VIRTIO_BLK_T")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum T {
+    #[doc(alias = "VIRTIO_BLK_T_IN")]
+    In = 0,
+
+    #[doc(alias = "VIRTIO_BLK_T_OUT")]
+    Out = 1,
+
+    #[doc(alias = "VIRTIO_BLK_T_FLUSH")]
+    Flush = 4,
+
+    #[doc(alias = "VIRTIO_BLK_T_GET_ID")]
+    GetId = 8,
+
+    #[doc(alias = "VIRTIO_BLK_T_GET_LIFETIME")]
+    GetLifetime = 10,
+
+    #[doc(alias = "VIRTIO_BLK_T_DISCARD")]
+    Discard = 11,
+
+    #[doc(alias = "VIRTIO_BLK_T_WRITE_ZEROES")]
+    WriteZeroes = 13,
+
+    #[doc(alias = "VIRTIO_BLK_T_SECURE_ERASE")]
+    SecureErase = 14,
+
+    /// The device writes the starting sector of the appended data as
+    /// `le64` before the status byte.
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_APPEND")]
+    ZoneAppend = 15,
+
+    /// The device writes a [`ZoneReport`] followed by [`ZoneDescriptor`]s.
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_REPORT")]
+    ZoneReport = 16,
+
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_OPEN")]
+    ZoneOpen = 18,
+
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_CLOSE")]
+    ZoneClose = 20,
+
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_FINISH")]
+    ZoneFinish = 22,
+
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_RESET")]
+    ZoneReset = 24,
+
+    #[doc(alias = "VIRTIO_BLK_T_ZONE_RESET_ALL")]
+    ZoneResetAll = 26,
+}
+
+/// Request Status
+///
+/// This is the trailing device-writable status byte of a request.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::block::S;
+///
+/// assert_eq!(S::try_from(0), Ok(S::Ok));
+/// assert_eq!(S::try_from(2), Ok(S::Unsupp));
+/// assert!(S::try_from(7).is_err());
+/// ```
+#[doc(alias = "VIRTIO_BLK_S")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum S {
+    #[doc(alias = "VIRTIO_BLK_S_OK")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_BLK_S_IOERR")]
+    IoErr = 1,
+
+    #[doc(alias = "VIRTIO_BLK_S_UNSUPP")]
+    Unsupp = 2,
+
+    #[doc(alias = "VIRTIO_BLK_S_ZONE_INVALID_CMD")]
+    ZoneInvalidCmd = 3,
+
+    #[doc(alias = "VIRTIO_BLK_S_ZONE_UNALIGNED_WP")]
+    ZoneUnalignedWp = 4,
+
+    #[doc(alias = "VIRTIO_BLK_S_ZONE_OPEN_RESOURCE")]
+    ZoneOpenResource = 5,
+
+    #[doc(alias = "VIRTIO_BLK_S_ZONE_ACTIVE_RESOURCE")]
+    ZoneActiveResource = 6,
+}
+
+endian_bitflags! {
+    /// Discard and Write Zeroes Flags
+    pub struct DiscardWriteZeroesF: le32 {
+        /// The device may deallocate the specified range of sectors for
+        /// [`VIRTIO_BLK_T_WRITE_ZEROES`](T::WriteZeroes).
+        #[doc(alias = "VIRTIO_BLK_WRITE_ZEROES_FLAG_UNMAP")]
+        const UNMAP = 1 << 0;
+    }
+}
+
+/// Discard, Write Zeroes, and Secure Erase Segment
+///
+/// This is the data of [`VIRTIO_BLK_T_DISCARD`](T::Discard),
+/// [`VIRTIO_BLK_T_WRITE_ZEROES`](T::WriteZeroes), and
+/// [`VIRTIO_BLK_T_SECURE_ERASE`](T::SecureErase) requests.
+///
+/// A request carries one or more segments.
+/// The device configuration limits segments per request:
+///
+/// - [`max_discard_seg`](ConfigVolatileFieldAccess::max_discard_seg) segments with at most
+///   [`max_discard_sectors`](ConfigVolatileFieldAccess::max_discard_sectors) sectors each
+///   for discard, aligned to
+///   [`discard_sector_alignment`](ConfigVolatileFieldAccess::discard_sector_alignment),
+/// - [`max_write_zeroes_seg`](ConfigVolatileFieldAccess::max_write_zeroes_seg) segments with at most
+///   [`max_write_zeroes_sectors`](ConfigVolatileFieldAccess::max_write_zeroes_sectors) sectors each
+///   for write zeroes, and
+/// - [`max_secure_erase_seg`](ConfigVolatileFieldAccess::max_secure_erase_seg) segments with at most
+///   [`max_secure_erase_sectors`](ConfigVolatileFieldAccess::max_secure_erase_sectors) sectors each
+///   for secure erase, aligned to
+///   [`secure_erase_sector_alignment`](ConfigVolatileFieldAccess::secure_erase_sector_alignment).
+///
+/// [`UNMAP`](DiscardWriteZeroesF::UNMAP) must be unset for discard and secure erase.
+#[doc(alias = "virtio_blk_discard_write_zeroes")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct DiscardWriteZeroes {
+    /// The starting sector.
+    pub sector: le64,
+    /// The number of sectors.
+    pub num_sectors: le32,
+    pub flags: DiscardWriteZeroesF,
+}
+
+impl DiscardWriteZeroes {
+    /// Creates a new segment.
+    pub const fn new(sector: u64, num_sectors: u32, flags: DiscardWriteZeroesF) -> Self {
+        Self {
+            sector: le64::from_ne(sector),
+            num_sectors: le32::from_ne(num_sectors),
+            flags,
+        }
+    }
+}
+
+/// Zone Report Header
+///
+/// This is the beginning of the device-writable data of a
+/// [`VIRTIO_BLK_T_ZONE_REPORT`](T::ZoneReport) request.
+/// `nr_zones` [`ZoneDescriptor`]s follow.
+#[doc(alias = "virtio_blk_zone_report")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ZoneReport {
+    /// The number of reported zones.
+    pub nr_zones: le64,
+    pub reserved: [u8; 56],
+}
+
+/// Zone Descriptor
+#[doc(alias = "virtio_blk_zone_descriptor")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ZoneDescriptor {
+    /// The zone capacity in 512-byte sectors.
+    pub z_cap: le64,
+    /// The starting sector of the zone.
+    pub z_start: le64,
+    /// The write pointer of the zone.
+    pub z_wp: le64,
+    /// The zone type, see [`ZoneType`].
+    pub z_type: u8,
+    /// The zone state, see [`ZoneState`].
+    pub z_state: u8,
+    pub reserved: [u8; 38],
+}
+
+/// Zone Type
+#[doc(alias = "VIRTIO_BLK_ZT")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ZoneType {
+    /// Conventional zone
+    #[doc(alias = "VIRTIO_BLK_ZT_CONV")]
+    Conv = 1,
+
+    /// Sequential write required zone
+    #[doc(alias = "VIRTIO_BLK_ZT_SWR")]
+    Swr = 2,
+
+    /// Sequential write preferred zone
+    #[doc(alias = "VIRTIO_BLK_ZT_SWP")]
+    Swp = 3,
+}
+
+/// Zone State
+#[doc(alias = "VIRTIO_BLK_ZS")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ZoneState {
+    /// Not a write pointer zone (conventional zone)
+    #[doc(alias = "VIRTIO_BLK_ZS_NOT_WP")]
+    NotWp = 0,
+
+    #[doc(alias = "VIRTIO_BLK_ZS_EMPTY")]
+    Empty = 1,
+
+    /// Implicitly open
+    #[doc(alias = "VIRTIO_BLK_ZS_IOPEN")]
+    Iopen = 2,
+
+    /// Explicitly open
+    #[doc(alias = "VIRTIO_BLK_ZS_EOPEN")]
+    Eopen = 3,
+
+    #[doc(alias = "VIRTIO_BLK_ZS_CLOSED")]
+    Closed = 4,
+
+    /// Read-only
+    #[doc(alias = "VIRTIO_BLK_ZS_RDONLY")]
+    Rdonly = 13,
+
+    #[doc(alias = "VIRTIO_BLK_ZS_FULL")]
+    Full = 14,
+
+    #[doc(alias = "VIRTIO_BLK_ZS_OFFLINE")]
+    Offline = 15,
+}
+
+le_bytes_impl! {
+    Req {
+        type_: le32,
+        reserved: le32,
+        sector: le64,
+    }
+}
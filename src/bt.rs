@@ -0,0 +1,121 @@
+//! Bluetooth Device
+//!
+//! <div class="warning">
+//!
+//! This device is not part of a released VIRTIO specification yet.
+//! These definitions follow the Linux `virtio_bt.h` UAPI header.
+//!
+//! </div>
+//!
+//! The Bluetooth device has two virtqueues:
+//!
+//! - `txq` (0) carries HCI commands, ACL data, and SCO data from the driver to the controller.
+//! - `rxq` (1) carries HCI events, ACL data, and SCO data from the controller to the driver.
+//!
+//! Every buffer starts with the one-byte HCI packet type (as in the HCI UART transport), followed by the HCI packet.
+//! There are no separate virtqueues per HCI packet type.
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::bt::F;
+use crate::le16;
+
+/// Controller Type
+#[doc(alias = "VIRTIO_BT_CONFIG_TYPE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Type {
+    #[doc(alias = "VIRTIO_BT_CONFIG_TYPE_PRIMARY")]
+    Primary = 0,
+
+    #[doc(alias = "VIRTIO_BT_CONFIG_TYPE_AMP")]
+    Amp = 1,
+}
+
+/// Controller Vendor
+///
+/// Only meaningful if [`VIRTIO_BT_F_VND_HCI`](F::VND_HCI) negotiated.
+#[doc(alias = "VIRTIO_BT_CONFIG_VENDOR")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Vendor {
+    #[doc(alias = "VIRTIO_BT_CONFIG_VENDOR_NONE")]
+    None = 0,
+
+    #[doc(alias = "VIRTIO_BT_CONFIG_VENDOR_ZEPHYR")]
+    Zephyr = 1,
+
+    #[doc(alias = "VIRTIO_BT_CONFIG_VENDOR_INTEL")]
+    Intel = 2,
+
+    #[doc(alias = "VIRTIO_BT_CONFIG_VENDOR_REALTEK")]
+    Realtek = 3,
+}
+
+/// Bluetooth Device Configuration Layout
+///
+/// This is the packed layout used if [`VIRTIO_BT_F_CONFIG_V2`](F::CONFIG_V2) is not negotiated.
+/// Since `vendor` and `msft_opcode` are not naturally aligned, they are modeled as little-endian byte arrays.
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_bt_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// See [`Type`].
+    #[access(ReadOnly)]
+    type_: u8,
+
+    /// See [`Vendor`].
+    #[access(ReadOnly)]
+    vendor: [u8; 2],
+
+    /// Only if [`VIRTIO_BT_F_MSFT_EXT`](F::MSFT_EXT) negotiated.
+    #[access(ReadOnly)]
+    msft_opcode: [u8; 2],
+}
+
+/// Bluetooth Device Configuration Layout (Version 2)
+///
+/// This is the aligned layout used if [`VIRTIO_BT_F_CONFIG_V2`](F::CONFIG_V2) is negotiated.
+///
+/// Use [`ConfigV2VolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_bt_config_v2")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct ConfigV2 {
+    /// See [`Type`].
+    #[access(ReadOnly)]
+    type_: u8,
+
+    #[access(ReadOnly)]
+    alignment: u8,
+
+    /// See [`Vendor`].
+    #[access(ReadOnly)]
+    vendor: le16,
+
+    /// Only if [`VIRTIO_BT_F_MSFT_EXT`](F::MSFT_EXT) negotiated.
+    #[access(ReadOnly)]
+    msft_opcode: le16,
+}
@@ -0,0 +1,140 @@
+use core::{array, mem};
+
+use crate::{le128, le16, le32, le64};
+
+/// Implements manual little-endian byte conversion for plain structs.
+///
+/// The fields have to be listed in declaration order without any padding in between.
+/// Fields can be integers, endian integers, byte arrays, flags, or other structs using this macro.
+/// This is checked at compile time.
+/// This does not depend on the `zerocopy` feature.
+macro_rules! le_bytes_impl {
+    (
+        $Struct:ident {
+            $($field:ident: $T:ty),* $(,)?
+        }
+    ) => {
+        impl $Struct {
+            /// The size of this struct in bytes.
+            pub const SIZE: usize = 0 $(+ ::core::mem::size_of::<$T>())*;
+
+            /// Reads this struct from the beginning of `bytes`.
+            ///
+            /// Returns `None` if `bytes` is shorter than [`SIZE`](Self::SIZE).
+            /// This does not require the `zerocopy` feature.
+            pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+                let bytes = bytes.get(..Self::SIZE)?;
+                Some(<Self as crate::bytes::Field>::read(bytes))
+            }
+
+            /// Returns the memory representation of this struct.
+            ///
+            /// This does not require the `zerocopy` feature.
+            pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+                let mut bytes = [0; Self::SIZE];
+                crate::bytes::Field::write(self, &mut bytes);
+                bytes
+            }
+        }
+
+        impl crate::bytes::Field for $Struct {
+            fn read(bytes: &[u8]) -> Self {
+                $(
+                    let (field, bytes) = bytes.split_at(::core::mem::size_of::<$T>());
+                    let $field = <$T as crate::bytes::Field>::read(field);
+                )*
+                debug_assert!(bytes.is_empty());
+                Self { $($field),* }
+            }
+
+            fn write(&self, bytes: &mut [u8]) {
+                $(
+                    let (field, bytes) = bytes.split_at_mut(::core::mem::size_of::<$T>());
+                    crate::bytes::Field::write(&{ self.$field }, field);
+                )*
+                debug_assert!(bytes.is_empty());
+            }
+        }
+
+        const _: () = {
+            let offset = 0;
+            $(
+                assert!(::core::mem::offset_of!($Struct, $field) == offset);
+                let offset = offset + ::core::mem::size_of::<$T>();
+            )*
+            assert!(offset == ::core::mem::size_of::<$Struct>());
+        };
+    };
+}
+
+/// A struct field with a fixed little-endian memory representation.
+pub(crate) trait Field {
+    /// Reads the field from `bytes`, which are exactly as long as the field.
+    fn read(bytes: &[u8]) -> Self;
+
+    /// Writes the field to `bytes`, which are exactly as long as the field.
+    fn write(&self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_field {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl Field for $T {
+                fn read(bytes: &[u8]) -> Self {
+                    Self::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                fn write(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_field!(u8, u16, u32, u64, u128, le16, le32, le64, le128);
+
+impl<T: Field, const N: usize> Field for [T; N] {
+    fn read(bytes: &[u8]) -> Self {
+        let mut chunks = bytes.chunks_exact(mem::size_of::<T>());
+        array::from_fn(|_| T::read(chunks.next().unwrap()))
+    }
+
+    fn write(&self, bytes: &mut [u8]) {
+        let chunks = bytes.chunks_exact_mut(mem::size_of::<T>());
+        for (elem, chunk) in self.iter().zip(chunks) {
+            elem.write(chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::console::Control;
+    use crate::net::{Hdr, HdrF, HdrGso};
+    use crate::{le16, le32};
+
+    #[test]
+    fn roundtrip() {
+        let bytes = [1, 0, 0, 0, 6, 0, 1, 0];
+        let control = Control::from_bytes(&bytes).unwrap();
+        assert_eq!(control.id, le32::from_ne(1));
+        assert_eq!(control.event, le16::from_ne(6));
+        assert_eq!(control.value, le16::from_ne(1));
+        assert_eq!(control.to_bytes(), bytes);
+
+        assert!(Control::from_bytes(&bytes[..7]).is_none());
+    }
+
+    #[test]
+    fn roundtrip_flags() {
+        let bytes = [1, 4, 0x36, 0, 0xdc, 5, 0x22, 0, 0x10, 0, 1, 0];
+        let hdr = Hdr::from_bytes(&bytes).unwrap();
+        assert_eq!(hdr.flags, HdrF::NEEDS_CSUM);
+        assert_eq!(hdr.gso_type, HdrGso::TCPV6);
+        assert_eq!(hdr.hdr_len, le16::from_ne(0x36));
+        assert_eq!(hdr.gso_size, le16::from_ne(1500));
+        assert_eq!(hdr.num_buffers, le16::from_ne(1));
+        assert_eq!(hdr.to_bytes(), bytes);
+    }
+}
@@ -0,0 +1,223 @@
+//! CAN Device
+//!
+//! The CAN device has a `txq`, an `rxq`, and a `controlq`.
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::can::F;
+use crate::{le16, le32};
+
+endian_bitflags! {
+    /// CAN Device Status Flags
+    #[doc(alias = "VIRTIO_CAN_S")]
+    pub struct S: le16 {
+        /// The CAN controller is in bus-off state and has to be restarted via
+        /// [`VIRTIO_CAN_SET_CTRL_MODE_START`](MsgType::SetCtrlModeStart).
+        #[doc(alias = "VIRTIO_CAN_S_CTRL_BUSOFF")]
+        const CTRL_BUSOFF = 1 << 0;
+    }
+}
+
+/// CAN Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_can_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    #[access(ReadOnly)]
+    status: S,
+}
+
+/// Message Type
+#[doc(alias = "VIRTIO_CAN_T")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum MsgType {
+    /// Transmit a frame ([`TxOut`]).
+    #[doc(alias = "VIRTIO_CAN_TX")]
+    Tx = 0x0001,
+
+    /// Received frame ([`Rx`]).
+    #[doc(alias = "VIRTIO_CAN_RX")]
+    Rx = 0x0101,
+
+    /// Start the CAN controller ([`ControlOut`]).
+    #[doc(alias = "VIRTIO_CAN_SET_CTRL_MODE_START")]
+    SetCtrlModeStart = 0x0201,
+
+    /// Stop the CAN controller ([`ControlOut`]).
+    #[doc(alias = "VIRTIO_CAN_SET_CTRL_MODE_STOP")]
+    SetCtrlModeStop = 0x0202,
+}
+
+/// Request Result
+#[doc(alias = "VIRTIO_CAN_RESULT")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Res {
+    #[doc(alias = "VIRTIO_CAN_RESULT_OK")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_CAN_RESULT_NOT_OK")]
+    NotOk = 1,
+}
+
+endian_bitflags! {
+    /// Frame Flags
+    #[doc(alias = "VIRTIO_CAN_FLAGS")]
+    pub struct Flags: le32 {
+        /// The frame uses a 29-bit extended CAN ID (or a standard 11-bit CAN ID otherwise).
+        #[doc(alias = "VIRTIO_CAN_FLAGS_EXTENDED")]
+        const EXTENDED = 0x8000;
+
+        /// The frame is a CAN FD frame.
+        ///
+        /// Only if [`VIRTIO_CAN_F_CAN_FD`](F::CAN_FD) negotiated.
+        #[doc(alias = "VIRTIO_CAN_FLAGS_FD")]
+        const FD = 0x4000;
+
+        /// The frame is a remote transmission request frame.
+        ///
+        /// Only if [`VIRTIO_CAN_F_RTR_FRAMES`](F::RTR_FRAMES) negotiated.
+        #[doc(alias = "VIRTIO_CAN_FLAGS_RTR")]
+        const RTR = 0x2000;
+    }
+}
+
+/// The maximum length of the payload of a CAN FD frame.
+pub const MAX_SDU_LEN: usize = 64;
+
+/// Transmit Frame
+///
+/// This is the device-readable part of a [`VIRTIO_CAN_TX`](MsgType::Tx) request.
+#[doc(alias = "virtio_can_tx_out")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct TxOut {
+    /// See [`MsgType`].
+    pub msg_type: le16,
+    /// The number of valid bytes in `sdu`.
+    pub length: le16,
+    pub reserved_classic_dlc: u8,
+    pub padding: u8,
+    pub reserved_xl_priority: le16,
+    pub flags: Flags,
+    pub can_id: le32,
+    pub sdu: [u8; MAX_SDU_LEN],
+}
+
+/// Transmit Result
+///
+/// This is the device-writable part of a [`VIRTIO_CAN_TX`](MsgType::Tx) request.
+#[doc(alias = "virtio_can_tx_in")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct TxIn {
+    /// See [`Res`].
+    pub result: u8,
+}
+
+/// Received Frame
+#[doc(alias = "virtio_can_rx")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Rx {
+    /// See [`MsgType`].
+    pub msg_type: le16,
+    /// The number of valid bytes in `sdu`.
+    pub length: le16,
+    pub reserved_classic_dlc: u8,
+    pub padding: u8,
+    pub reserved_xl_priority: le16,
+    pub flags: Flags,
+    pub can_id: le32,
+    pub sdu: [u8; MAX_SDU_LEN],
+}
+
+/// Control Request
+#[doc(alias = "virtio_can_control_out")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ControlOut {
+    /// See [`MsgType`].
+    pub msg_type: le16,
+}
+
+le_bytes_impl! {
+    ControlOut {
+        msg_type: le16,
+    }
+}
+
+/// Control Result
+#[doc(alias = "virtio_can_control_in")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ControlIn {
+    /// See [`Res`].
+    pub result: u8,
+}
+
+le_bytes_impl! {
+    ControlIn {
+        result: u8,
+    }
+}
@@ -0,0 +1,232 @@
+//! Definitions for Virtio over Channel I/O.
+//!
+//! Unlike most other structures in this crate, most structures for Virtio over channel I/O are big-endian.
+
+use core::mem;
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::{be16, be32, be64, le32};
+
+/// Channel Command Code
+#[doc(alias = "CCW_CMD")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Cmd {
+    /// Set up a virtqueue using [`VqInfoBlock`] (or [`VqInfoBlockLegacy`]).
+    #[doc(alias = "CCW_CMD_SET_VQ")]
+    SetVq = 0x13,
+
+    /// Reset the device.
+    #[doc(alias = "CCW_CMD_VDEV_RESET")]
+    VdevReset = 0x33,
+
+    /// Set up classic queue indicators.
+    #[doc(alias = "CCW_CMD_SET_IND")]
+    SetInd = 0x43,
+
+    /// Set up the configuration change indicator.
+    #[doc(alias = "CCW_CMD_SET_CONF_IND")]
+    SetConfInd = 0x53,
+
+    /// Set up adapter (thin interrupt) queue indicators using [`ThinintArea`].
+    #[doc(alias = "CCW_CMD_SET_IND_ADAPTER")]
+    SetIndAdapter = 0x73,
+
+    /// Read device feature bits using [`FeatureDesc`].
+    #[doc(alias = "CCW_CMD_READ_FEAT")]
+    ReadFeat = 0x12,
+
+    /// Write driver feature bits using [`FeatureDesc`].
+    #[doc(alias = "CCW_CMD_WRITE_FEAT")]
+    WriteFeat = 0x11,
+
+    /// Read the device-specific configuration.
+    #[doc(alias = "CCW_CMD_READ_CONF")]
+    ReadConf = 0x22,
+
+    /// Write the device-specific configuration.
+    #[doc(alias = "CCW_CMD_WRITE_CONF")]
+    WriteConf = 0x21,
+
+    /// Write the device status.
+    #[doc(alias = "CCW_CMD_WRITE_STATUS")]
+    WriteStatus = 0x31,
+
+    /// Read the virtqueue configuration using [`VqConfigBlock`].
+    #[doc(alias = "CCW_CMD_READ_VQ_CONF")]
+    ReadVqConf = 0x32,
+
+    /// Negotiate the revision using [`RevInfo`].
+    #[doc(alias = "CCW_CMD_SET_VIRTIO_REV")]
+    SetVirtioRev = 0x83,
+
+    /// Read the device status (only for revision 2 or later).
+    #[doc(alias = "CCW_CMD_READ_STATUS")]
+    ReadStatus = 0x72,
+}
+
+/// Revision Information
+///
+/// This header is followed by `length` bytes of revision-specific data.
+#[doc(alias = "virtio_rev_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RevInfo {
+    /// The revision.
+    ///
+    /// Revision 0 is the legacy interface, revision 1 is the Virtio 1.0
+    /// interface, and revision 2 adds [`CCW_CMD_READ_STATUS`](Cmd::ReadStatus).
+    pub revision: be16,
+
+    /// The length of the revision-specific data.
+    pub length: be16,
+}
+
+/// Virtqueue Configuration Block
+#[doc(alias = "vq_config_block")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct VqConfigBlock {
+    /// The index of the virtqueue.
+    pub index: be16,
+
+    /// The maximum size of the virtqueue.
+    pub max_num: be16,
+}
+
+/// Virtqueue Information Block
+#[doc(alias = "vq_info_block")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct VqInfoBlock {
+    /// The guest-physical address of the Descriptor Area.
+    pub desc: be64,
+    pub res0: be32,
+
+    /// The index of the virtqueue.
+    pub index: be16,
+
+    /// The size of the virtqueue.
+    pub num: be16,
+
+    /// The guest-physical address of the Driver Area.
+    pub driver: be64,
+
+    /// The guest-physical address of the Device Area.
+    pub device: be64,
+}
+
+/// Legacy Virtqueue Information Block
+///
+/// This is used with revision 0 instead of [`VqInfoBlock`].
+#[doc(alias = "vq_info_block_legacy")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct VqInfoBlockLegacy {
+    /// The guest-physical address of the virtqueue.
+    pub queue: be64,
+
+    /// The alignment of the virtqueue.
+    pub align: be32,
+
+    /// The index of the virtqueue.
+    pub index: be16,
+
+    /// The size of the virtqueue.
+    pub num: be16,
+}
+
+/// Feature Descriptor
+///
+/// Feature bits are transferred in 32-bit windows selected by `index`.
+#[doc(alias = "virtio_feature_desc")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct FeatureDesc {
+    /// The feature bits of the selected window.
+    ///
+    /// Note that this field is little-endian.
+    pub features: le32,
+
+    /// The index of the 32-bit feature bit window.
+    pub index: u8,
+}
+
+/// Thin Interrupt Area
+#[doc(alias = "virtio_thinint_area")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct ThinintArea {
+    /// The guest-physical address of the summary indicator.
+    pub summary_indicator: be64,
+
+    /// The guest-physical address of the queue indicators.
+    pub indicator: be64,
+
+    /// The bit offset of the queue indicators of this device within `indicator`.
+    pub bit_nr: be64,
+
+    /// The interruption subclass.
+    pub isc: u8,
+}
+
+const _: () = assert!(mem::size_of::<VqInfoBlock>() == 32);
+const _: () = assert!(mem::size_of::<VqInfoBlockLegacy>() == 16);
+const _: () = assert!(mem::size_of::<FeatureDesc>() == 5);
+const _: () = assert!(mem::size_of::<ThinintArea>() == 25);
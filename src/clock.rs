@@ -0,0 +1,179 @@
+//! Timer/Clock Device
+//!
+//! <div class="warning">
+//!
+//! This device is not part of a released VIRTIO specification yet.
+//! These definitions follow the RTC device proposal and may change.
+//!
+//! </div>
+//!
+//! The clock device has a `requestq` and, if [`VIRTIO_RTC_F_ALARM`](F::ALARM) is negotiated, an `alarmq`.
+//! It has no device-specific configuration space.
+//!
+//! Each request starts with a [`ReqHead`] and each response starts with a [`RespHead`].
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+pub use super::features::clock::F;
+use crate::{le16, le64};
+
+/// Request Type
+#[doc(alias = "VIRTIO_RTC_REQ")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Req {
+    /// Read a clock.
+    #[doc(alias = "VIRTIO_RTC_REQ_READ")]
+    Read = 0x0001,
+
+    /// Read a clock together with a hardware counter.
+    #[doc(alias = "VIRTIO_RTC_REQ_READ_CROSS")]
+    ReadCross = 0x0002,
+
+    /// Query the number of clocks.
+    #[doc(alias = "VIRTIO_RTC_REQ_CFG")]
+    Cfg = 0x1000,
+
+    /// Query the capabilities of a clock.
+    #[doc(alias = "VIRTIO_RTC_REQ_CLOCK_CAP")]
+    ClockCap = 0x1001,
+
+    /// Query whether a clock supports cross-timestamping with a hardware counter.
+    #[doc(alias = "VIRTIO_RTC_REQ_CROSS_CAP")]
+    CrossCap = 0x1002,
+}
+
+/// Request Header
+#[doc(alias = "virtio_rtc_req_head")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqHead {
+    /// See [`Req`].
+    pub msg_type: le16,
+    pub reserved: [u8; 6],
+}
+
+/// Response Status
+#[doc(alias = "VIRTIO_RTC_S")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Status {
+    #[doc(alias = "VIRTIO_RTC_S_OK")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_RTC_S_EOPNOTSUPP")]
+    Eopnotsupp = 2,
+
+    #[doc(alias = "VIRTIO_RTC_S_ENODEV")]
+    Enodev = 3,
+
+    #[doc(alias = "VIRTIO_RTC_S_EINVAL")]
+    Einval = 4,
+
+    #[doc(alias = "VIRTIO_RTC_S_EIO")]
+    Eio = 5,
+}
+
+/// Response Header
+#[doc(alias = "virtio_rtc_resp_head")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespHead {
+    /// See [`Status`].
+    pub status: u8,
+    pub reserved: [u8; 7],
+}
+
+/// Clock Type
+#[doc(alias = "VIRTIO_RTC_CLOCK")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ClockType {
+    #[doc(alias = "VIRTIO_RTC_CLOCK_UTC")]
+    Utc = 0,
+
+    #[doc(alias = "VIRTIO_RTC_CLOCK_TAI")]
+    Tai = 1,
+
+    #[doc(alias = "VIRTIO_RTC_CLOCK_MONOTONIC")]
+    Monotonic = 2,
+}
+
+/// [`VIRTIO_RTC_REQ_READ`](Req::Read) Request
+#[doc(alias = "virtio_rtc_req_read")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqRead {
+    pub head: ReqHead,
+    pub clock_id: le16,
+    pub reserved: [u8; 6],
+}
+
+/// [`VIRTIO_RTC_REQ_READ`](Req::Read) Response
+#[doc(alias = "virtio_rtc_resp_read")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespRead {
+    pub head: RespHead,
+    /// The clock reading in nanoseconds.
+    pub clock_reading: le64,
+}
+
+/// [`VIRTIO_RTC_REQ_CFG`](Req::Cfg) Response
+#[doc(alias = "virtio_rtc_resp_cfg")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespCfg {
+    pub head: RespHead,
+    /// The number of clocks, identified by the clock IDs `0..num_clocks`.
+    pub num_clocks: le16,
+    pub reserved: [u8; 6],
+}
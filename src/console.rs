@@ -0,0 +1,294 @@
+//! Console Device
+//!
+//! The console device has a `receiveq` and a `transmitq` for port 0.
+//! If [`VIRTIO_CONSOLE_F_MULTIPORT`](F::MULTIPORT) is negotiated, it also has a
+//! `control receiveq` and a `control transmitq` carrying [`Control`] messages,
+//! and a `receiveq` and `transmitq` for each further port.
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::{ReadOnly, RestrictAccess, Writable, WriteOnly};
+use volatile::VolatilePtr;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::console::F;
+use crate::{le16, le32};
+
+/// Console Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_console_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// Only if [`VIRTIO_CONSOLE_F_SIZE`](F::SIZE) negotiated.
+    #[access(ReadOnly)]
+    cols: le16,
+
+    /// Only if [`VIRTIO_CONSOLE_F_SIZE`](F::SIZE) negotiated.
+    #[access(ReadOnly)]
+    rows: le16,
+
+    /// Only if [`VIRTIO_CONSOLE_F_MULTIPORT`](F::MULTIPORT) negotiated.
+    #[access(ReadOnly)]
+    max_nr_ports: le32,
+
+    /// Only if [`VIRTIO_CONSOLE_F_EMERG_WRITE`](F::EMERG_WRITE) negotiated.
+    ///
+    /// Writing a character to this field outputs it on port 0,
+    /// even before the virtqueues are set up.
+    #[access(WriteOnly)]
+    emerg_wr: le32,
+}
+
+defmt_format_impl! {
+    Config {
+        cols,
+        rows,
+        max_nr_ports,
+        emerg_wr,
+    }
+}
+
+/// Writes `byte` to the emergency console.
+///
+/// This writes `byte` to [`emerg_wr`](ConfigVolatileFieldAccess::emerg_wr),
+/// which outputs it on port 0 even before the virtqueues are set up.
+/// This is useful for early boot and panic output.
+///
+/// This requires [`VIRTIO_CONSOLE_F_EMERG_WRITE`](F::EMERG_WRITE) to have been negotiated.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::console::{self, Config};
+/// use volatile::VolatilePtr;
+///
+/// fn write_str(config: VolatilePtr<'_, Config>, s: &str) {
+///     for byte in s.bytes() {
+///         console::emergency_write(config, byte);
+///     }
+/// }
+/// ```
+pub fn emergency_write<A>(config: VolatilePtr<'_, Config, A>, byte: u8)
+where
+    A: RestrictAccess<WriteOnly>,
+    A::Restricted: Writable,
+{
+    config.emerg_wr().write(le32::from_ne(byte.into()));
+}
+
+/// Control Message
+///
+/// If the message is a [`VIRTIO_CONSOLE_RESIZE`](Event::Resize) message, it is followed by [`Resize`].
+/// If the message is a [`VIRTIO_CONSOLE_PORT_NAME`](Event::PortName) message, it is followed by the UTF-8 name of the port.
+/// See [`Control::parse`].
+#[doc(alias = "virtio_console_control")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Control {
+    /// Port number
+    pub id: le32,
+    /// The kind of control event (see [`Event`]).
+    pub event: le16,
+    /// Extra information for the event
+    pub value: le16,
+}
+
+defmt_format_impl! {
+    Control {
+        id,
+        event,
+        value,
+    }
+}
+
+/// Control Event
+#[doc(alias = "VIRTIO_CONSOLE")]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Event {
+    /// Sent by the driver at initialization to indicate that it is ready to receive control messages.
+    ///
+    /// A value of 1 indicates success, and 0 indicates failure.
+    #[doc(alias = "VIRTIO_CONSOLE_DEVICE_READY")]
+    DeviceReady = 0,
+
+    /// Sent by the device, to create a new port.
+    #[doc(alias = "VIRTIO_CONSOLE_DEVICE_ADD")]
+    DeviceAdd = 1,
+
+    /// Sent by the device, to remove an existing port.
+    #[doc(alias = "VIRTIO_CONSOLE_DEVICE_REMOVE")]
+    DeviceRemove = 2,
+
+    /// Sent by the driver in response to the device's [`VIRTIO_CONSOLE_DEVICE_ADD`](Self::DeviceAdd) message,
+    /// to indicate that the port is ready to be used.
+    ///
+    /// A value of 1 indicates success, and 0 indicates failure.
+    #[doc(alias = "VIRTIO_CONSOLE_PORT_READY")]
+    PortReady = 3,
+
+    /// Sent by the device to nominate a port as a console port.
+    #[doc(alias = "VIRTIO_CONSOLE_CONSOLE_PORT")]
+    ConsolePort = 4,
+
+    /// Sent by the device to indicate a console size change.
+    ///
+    /// The message is followed by [`Resize`].
+    #[doc(alias = "VIRTIO_CONSOLE_RESIZE")]
+    Resize = 5,
+
+    /// Sent by the device to indicate that a connection has been established to the port (value 1),
+    /// or that it has been closed (value 0).
+    /// Sent by the driver to indicate that the port has been opened (value 1) or closed (value 0).
+    #[doc(alias = "VIRTIO_CONSOLE_PORT_OPEN")]
+    PortOpen = 6,
+
+    /// Sent by the device to give a tag to the port.
+    ///
+    /// The message is followed by the UTF-8 name of the port.
+    #[doc(alias = "VIRTIO_CONSOLE_PORT_NAME")]
+    PortName = 7,
+}
+
+/// Console Resize
+///
+/// This follows a [`VIRTIO_CONSOLE_RESIZE`](Event::Resize) control message.
+#[doc(alias = "virtio_console_resize")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Resize {
+    pub cols: le16,
+    pub rows: le16,
+}
+
+defmt_format_impl! {
+    Resize {
+        cols,
+        rows,
+    }
+}
+
+/// Control Message Payload
+///
+/// See [`Control::parse`].
+#[derive(Clone, Copy, Debug)]
+pub enum Payload<'a> {
+    /// The payload of a [`VIRTIO_CONSOLE_RESIZE`](Event::Resize) message.
+    Resize(Resize),
+
+    /// The payload of a [`VIRTIO_CONSOLE_PORT_NAME`](Event::PortName) message.
+    ///
+    /// This should be valid UTF-8 (see [`core::str::from_utf8`]).
+    PortName(&'a [u8]),
+
+    /// The bytes following any other message.
+    Other(&'a [u8]),
+}
+
+impl Control {
+    /// Creates a new control message.
+    pub const fn new(id: u32, event: Event, value: u16) -> Self {
+        Self {
+            id: le32::from_ne(id),
+            event: le16::from_ne(event as u16),
+            value: le16::from_ne(value),
+        }
+    }
+
+    /// Creates a [`VIRTIO_CONSOLE_DEVICE_READY`](Event::DeviceReady) message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::console::Control;
+    ///
+    /// let control = Control::device_ready(true);
+    /// assert_eq!(control.to_bytes(), [0, 0, 0, 0, 0, 0, 1, 0]);
+    /// ```
+    pub const fn device_ready(ok: bool) -> Self {
+        Self::new(0, Event::DeviceReady, ok as u16)
+    }
+
+    /// Creates a [`VIRTIO_CONSOLE_PORT_READY`](Event::PortReady) message for port `id`.
+    pub const fn port_ready(id: u32, ok: bool) -> Self {
+        Self::new(id, Event::PortReady, ok as u16)
+    }
+
+    /// Creates a [`VIRTIO_CONSOLE_PORT_OPEN`](Event::PortOpen) message for port `id`.
+    pub const fn port_open(id: u32, open: bool) -> Self {
+        Self::new(id, Event::PortOpen, open as u16)
+    }
+
+    /// Parses a control message and its payload from `buf`.
+    ///
+    /// Returns `None` if `buf` is too short for the message or its payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::console::{Control, Event, Payload};
+    ///
+    /// let buf = [1, 0, 0, 0, 7, 0, 0, 0, b'c', b'o', b'm'];
+    /// let (control, payload) = Control::parse(&buf).unwrap();
+    /// assert_eq!(control.id.to_ne(), 1);
+    /// assert_eq!(Event::try_from(control.event.to_ne()), Ok(Event::PortName));
+    /// assert!(matches!(payload, Payload::PortName(b"com")));
+    /// ```
+    pub fn parse(buf: &[u8]) -> Option<(Self, Payload<'_>)> {
+        let control = Self::from_bytes(buf)?;
+        let rest = &buf[Self::SIZE..];
+        let payload = match Event::try_from(control.event.to_ne()) {
+            Ok(Event::Resize) => Payload::Resize(Resize::from_bytes(rest)?),
+            Ok(Event::PortName) => Payload::PortName(rest),
+            _ => Payload::Other(rest),
+        };
+        Some((control, payload))
+    }
+}
+
+le_bytes_impl! {
+    Control {
+        id: le32,
+        event: le16,
+        value: le16,
+    }
+}
+
+le_bytes_impl! {
+    Resize {
+        cols: le16,
+        rows: le16,
+    }
+}
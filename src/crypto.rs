@@ -0,0 +1,526 @@
+//! Crypto Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::crypto::F;
+use crate::{le32, le64};
+
+endian_bitflags! {
+    /// Crypto Device Status
+    #[doc(alias = "VIRTIO_CRYPTO_S")]
+    pub struct S: le32 {
+        /// The device is ready.
+        #[doc(alias = "VIRTIO_CRYPTO_S_HW_READY")]
+        const HW_READY = 1 << 0;
+    }
+}
+
+endian_bitflags! {
+    /// Crypto Services
+    ///
+    /// Each bit corresponds to a [`Service`].
+    #[doc(alias = "VIRTIO_CRYPTO_SERVICE")]
+    pub struct Services: le32 {
+        #[doc(alias = "VIRTIO_CRYPTO_SERVICE_CIPHER")]
+        const CIPHER = 1 << 0;
+
+        #[doc(alias = "VIRTIO_CRYPTO_SERVICE_HASH")]
+        const HASH = 1 << 1;
+
+        #[doc(alias = "VIRTIO_CRYPTO_SERVICE_MAC")]
+        const MAC = 1 << 2;
+
+        #[doc(alias = "VIRTIO_CRYPTO_SERVICE_AEAD")]
+        const AEAD = 1 << 3;
+
+        #[doc(alias = "VIRTIO_CRYPTO_SERVICE_AKCIPHER")]
+        const AKCIPHER = 1 << 4;
+    }
+}
+
+/// Crypto Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_crypto_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    #[access(ReadOnly)]
+    status: S,
+
+    /// The maximum number of data virtqueues exposed by
+    /// the device. The driver MAY use only one data queue,
+    /// or it can use more to achieve better performance.
+    #[access(ReadOnly)]
+    max_dataqueues: le32,
+
+    /// Crypto service offered.
+    #[access(ReadOnly)]
+    crypto_services: Services,
+
+    /// Cipher algorithms bits 0-31.
+    #[access(ReadOnly)]
+    cipher_algo_l: le32,
+
+    /// Cipher algorithms bits 32-63.
+    #[access(ReadOnly)]
+    cipher_algo_h: le32,
+
+    /// Hash algorithms bits.
+    #[access(ReadOnly)]
+    hash_algo: le32,
+
+    /// Mac algorithms bits 0-31.
+    #[access(ReadOnly)]
+    mac_algo_l: le32,
+
+    /// Mac algorithms bits 32-63.
+    #[access(ReadOnly)]
+    mac_algo_h: le32,
+
+    /// AEAD algorithms bits.
+    #[access(ReadOnly)]
+    aead_algo: le32,
+
+    /// The maximum length of cipher key in bytes.
+    #[access(ReadOnly)]
+    max_cipher_key_len: le32,
+
+    /// The maximum length of authenticated key in bytes.
+    #[access(ReadOnly)]
+    max_auth_key_len: le32,
+
+    /// Akcipher algorithms bits.
+    #[access(ReadOnly)]
+    akcipher_algo: le32,
+
+    /// The maximum size of the crypto request the device supports.
+    #[access(ReadOnly)]
+    max_size: le64,
+}
+
+/// Crypto Service
+#[doc(alias = "VIRTIO_CRYPTO_SERVICE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Service {
+    /// Cipher service
+    #[doc(alias = "VIRTIO_CRYPTO_SERVICE_CIPHER")]
+    Cipher = 0,
+
+    /// Hash service
+    #[doc(alias = "VIRTIO_CRYPTO_SERVICE_HASH")]
+    Hash = 1,
+
+    /// MAC (Message Authentication Codes) service
+    #[doc(alias = "VIRTIO_CRYPTO_SERVICE_MAC")]
+    Mac = 2,
+
+    /// AEAD (Authenticated Encryption with Associated Data) service
+    #[doc(alias = "VIRTIO_CRYPTO_SERVICE_AEAD")]
+    Aead = 3,
+
+    /// Asymmetric key cipher service
+    #[doc(alias = "VIRTIO_CRYPTO_SERVICE_AKCIPHER")]
+    Akcipher = 4,
+}
+
+/// Operation Code
+///
+/// Each opcode is composed of the [`Service`] in bits 8-15 and the
+/// service-specific operation in bits 0-7.
+#[doc(alias = "VIRTIO_CRYPTO_OPCODE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Opcode {
+    #[doc(alias = "VIRTIO_CRYPTO_CIPHER_ENCRYPT")]
+    CipherEncrypt = 0x0000,
+
+    #[doc(alias = "VIRTIO_CRYPTO_CIPHER_DECRYPT")]
+    CipherDecrypt = 0x0001,
+
+    #[doc(alias = "VIRTIO_CRYPTO_CIPHER_CREATE_SESSION")]
+    CipherCreateSession = 0x0002,
+
+    #[doc(alias = "VIRTIO_CRYPTO_CIPHER_DESTROY_SESSION")]
+    CipherDestroySession = 0x0003,
+
+    #[doc(alias = "VIRTIO_CRYPTO_HASH")]
+    Hash = 0x0100,
+
+    #[doc(alias = "VIRTIO_CRYPTO_HASH_CREATE_SESSION")]
+    HashCreateSession = 0x0102,
+
+    #[doc(alias = "VIRTIO_CRYPTO_HASH_DESTROY_SESSION")]
+    HashDestroySession = 0x0103,
+
+    #[doc(alias = "VIRTIO_CRYPTO_MAC")]
+    Mac = 0x0200,
+
+    #[doc(alias = "VIRTIO_CRYPTO_MAC_CREATE_SESSION")]
+    MacCreateSession = 0x0202,
+
+    #[doc(alias = "VIRTIO_CRYPTO_MAC_DESTROY_SESSION")]
+    MacDestroySession = 0x0203,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AEAD_ENCRYPT")]
+    AeadEncrypt = 0x0300,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AEAD_DECRYPT")]
+    AeadDecrypt = 0x0301,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AEAD_CREATE_SESSION")]
+    AeadCreateSession = 0x0302,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AEAD_DESTROY_SESSION")]
+    AeadDestroySession = 0x0303,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AKCIPHER_ENCRYPT")]
+    AkcipherEncrypt = 0x0400,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AKCIPHER_DECRYPT")]
+    AkcipherDecrypt = 0x0401,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AKCIPHER_SIGN")]
+    AkcipherSign = 0x0402,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AKCIPHER_VERIFY")]
+    AkcipherVerify = 0x0403,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AKCIPHER_CREATE_SESSION")]
+    AkcipherCreateSession = 0x0404,
+
+    #[doc(alias = "VIRTIO_CRYPTO_AKCIPHER_DESTROY_SESSION")]
+    AkcipherDestroySession = 0x0405,
+}
+
+/// Operation Status
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Status {
+    /// success.
+    #[doc(alias = "VIRTIO_CRYPTO_OK")]
+    Ok = 0,
+
+    /// any failure not covered by the other status codes.
+    #[doc(alias = "VIRTIO_CRYPTO_ERR")]
+    Err = 1,
+
+    /// authentication failed (only when AEAD decryption).
+    #[doc(alias = "VIRTIO_CRYPTO_BADMSG")]
+    BadMsg = 2,
+
+    /// operation or algorithm is unsupported.
+    #[doc(alias = "VIRTIO_CRYPTO_NOTSUPP")]
+    NotSupp = 3,
+
+    /// invalid session ID when executing crypto operations.
+    #[doc(alias = "VIRTIO_CRYPTO_INVSESS")]
+    InvSess = 4,
+
+    /// no free session ID (only when the VIRTIO_CRYPTO_F_REVISION_1
+    /// feature bit is negotiated).
+    #[doc(alias = "VIRTIO_CRYPTO_NOSPC")]
+    NoSpc = 5,
+
+    /// Signature verification failed (only when AKCIPHER verification).
+    #[doc(alias = "VIRTIO_CRYPTO_KEY_REJECTED")]
+    KeyRejected = 6,
+}
+
+/// Control Request Header
+#[doc(alias = "virtio_crypto_ctrl_header")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlHeader {
+    /// See [`Opcode`].
+    pub opcode: le32,
+    /// Service-specific algorithm.
+    pub algo: le32,
+    pub flag: le32,
+    pub reserved: le32,
+}
+
+le_bytes_impl! {
+    CtrlHeader {
+        opcode: le32,
+        algo: le32,
+        flag: le32,
+        reserved: le32,
+    }
+}
+
+/// Session Creation Input
+///
+/// This is the device-writable part of a session creation request.
+#[doc(alias = "virtio_crypto_create_session_input")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CreateSessionInput {
+    pub session_id: le64,
+    /// See [`Status`].
+    pub status: le32,
+    pub padding: le32,
+}
+
+/// Session Destruction Fixed-Length Fields
+#[doc(alias = "virtio_crypto_destroy_session_flf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct DestroySessionFlf {
+    pub session_id: le64,
+}
+
+/// Session Destruction Input
+///
+/// This is the device-writable part of a session destruction request.
+#[doc(alias = "virtio_crypto_destroy_session_input")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct DestroySessionInput {
+    /// See [`Status`].
+    pub status: u8,
+}
+
+/// Symmetric Algorithm Operation Type
+#[doc(alias = "VIRTIO_CRYPTO_SYM_OP")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum SymOp {
+    /// No operation
+    #[doc(alias = "VIRTIO_CRYPTO_SYM_OP_NONE")]
+    None = 0,
+
+    /// Cipher only operation on the data
+    #[doc(alias = "VIRTIO_CRYPTO_SYM_OP_CIPHER")]
+    Cipher = 1,
+
+    /// Chain any cipher with any hash or mac operation.
+    #[doc(alias = "VIRTIO_CRYPTO_SYM_OP_ALGORITHM_CHAINING")]
+    AlgorithmChaining = 2,
+}
+
+/// Cipher Direction
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Op {
+    #[doc(alias = "VIRTIO_CRYPTO_OP_ENCRYPT")]
+    Encrypt = 1,
+
+    #[doc(alias = "VIRTIO_CRYPTO_OP_DECRYPT")]
+    Decrypt = 2,
+}
+
+/// Size of [`SymCreateSessionFlf::op_flf`]
+#[doc(alias = "VIRTIO_CRYPTO_SYM_SESS_OP_SPEC_HDR_SIZE")]
+pub const SYM_SESS_OP_SPEC_HDR_SIZE: usize = 48;
+
+/// Symmetric Session Creation Fixed-Length Fields
+#[doc(alias = "virtio_crypto_sym_create_session_flf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct SymCreateSessionFlf {
+    /// Operation-specific fixed-length fields, such as [`CipherSessionFlf`].
+    pub op_flf: [u8; SYM_SESS_OP_SPEC_HDR_SIZE],
+    /// See [`SymOp`].
+    pub op_type: le32,
+    pub padding: le32,
+}
+
+/// Cipher Session Creation Fixed-Length Fields
+#[doc(alias = "virtio_crypto_cipher_session_flf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CipherSessionFlf {
+    /// Cipher algorithm.
+    pub algo: le32,
+    /// Length of the cipher key.
+    pub key_len: le32,
+    /// Encryption or decryption, see [`Op`].
+    pub op: le32,
+    pub padding: le32,
+}
+
+/// Data Request Header
+#[doc(alias = "virtio_crypto_op_header")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct OpHeader {
+    /// See [`Opcode`].
+    pub opcode: le32,
+    /// Service-specific algorithm.
+    pub algo: le32,
+    pub session_id: le64,
+    /// Control flag, see [`FLAG_SESSION_MODE`].
+    pub flag: le32,
+    pub padding: le32,
+}
+
+le_bytes_impl! {
+    OpHeader {
+        opcode: le32,
+        algo: le32,
+        session_id: le64,
+        flag: le32,
+        padding: le32,
+    }
+}
+
+/// Session mode
+///
+/// If set in [`OpHeader::flag`], the request is a session mode request.
+/// Otherwise, it is a stateless mode request.
+#[doc(alias = "VIRTIO_CRYPTO_FLAG_SESSION_MODE")]
+pub const FLAG_SESSION_MODE: u32 = 1;
+
+/// Size of [`SymDataFlf::op_type_flf`]
+#[doc(alias = "VIRTIO_CRYPTO_SYM_DATA_REQ_HDR_SIZE")]
+pub const SYM_DATA_REQ_HDR_SIZE: usize = 40;
+
+/// Symmetric Data Request Fixed-Length Fields
+#[doc(alias = "virtio_crypto_sym_data_flf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct SymDataFlf {
+    /// Operation-specific fixed-length fields, such as [`CipherDataFlf`].
+    pub op_type_flf: [u8; SYM_DATA_REQ_HDR_SIZE],
+    /// See [`SymOp`].
+    pub op_type: le32,
+    pub padding: le32,
+}
+
+/// Cipher Data Request Fixed-Length Fields
+#[doc(alias = "virtio_crypto_cipher_data_flf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CipherDataFlf {
+    /// Length of the initialization vector.
+    pub iv_len: le32,
+    /// Length of the source data.
+    pub src_data_len: le32,
+    /// Length of the destination data.
+    pub dst_data_len: le32,
+    pub padding: le32,
+}
+
+/// Data Request Input Header
+///
+/// This is the device-writable trailer of every data request.
+#[doc(alias = "virtio_crypto_inhdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct InHdr {
+    /// See [`Status`].
+    pub status: u8,
+}
+
+le_bytes_impl! {
+    InHdr {
+        status: u8,
+    }
+}
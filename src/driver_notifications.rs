@@ -6,6 +6,11 @@ use crate::le32;
 #[bitfield(u32, repr = le32, from = le32::from_ne, into = le32::to_ne)]
 pub struct NotificationData {
     /// VQ number to be notified.
+    ///
+    /// If [`VIRTIO_F_NOTIF_CONFIG_DATA`] has been negotiated, this is the
+    /// virtqueue identifier provided by the device instead.
+    ///
+    /// [`VIRTIO_F_NOTIF_CONFIG_DATA`]: crate::F::NOTIF_CONFIG_DATA
     pub vqn: u16,
 
     /// Offset
@@ -37,6 +42,109 @@ impl NotificationData {
     const NEXT_IDX_BITS: usize = 16;
     const NEXT_IDX_OFFSET: usize = 16;
 
+    /// Creates the notification data for a split virtqueue.
+    ///
+    /// `avail_idx` is the index of the available ring that the driver will write next.
+    /// Only use this if [`VIRTIO_F_NOTIFICATION_DATA`] has been negotiated.
+    /// Otherwise, the driver notifies the device by writing only the virtqueue index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// # #[cfg(not(feature = "pci"))]
+    /// # use virtio::mmio::NotificationData;
+    /// # #[cfg(feature = "pci")]
+    /// use virtio::pci::NotificationData;
+    ///
+    /// let data = NotificationData::for_split(2, 0x8003);
+    /// assert_eq!(data.into_bits().to_ne(), 0x8003_0002);
+    /// assert_eq!(data.next_off(), 3);
+    /// assert_eq!(data.next_wrap(), 1);
+    /// ```
+    ///
+    /// [`VIRTIO_F_NOTIFICATION_DATA`]: crate::F::NOTIFICATION_DATA
+    pub const fn for_split(vqn: u16, avail_idx: u16) -> Self {
+        Self::new().with_vqn(vqn).with_next_idx(avail_idx)
+    }
+
+    /// Creates the notification data for a packed virtqueue.
+    ///
+    /// `next_off` is the offset within the descriptor ring where the driver will write the next available descriptor.
+    /// `next_wrap` is the wrap counter referring to that descriptor.
+    /// Only use this if [`VIRTIO_F_NOTIFICATION_DATA`] has been negotiated.
+    /// Otherwise, the driver notifies the device by writing only the virtqueue index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// # #[cfg(not(feature = "pci"))]
+    /// # use virtio::mmio::NotificationData;
+    /// # #[cfg(feature = "pci")]
+    /// use virtio::pci::NotificationData;
+    ///
+    /// let data = NotificationData::for_packed(1, 5, true);
+    /// assert_eq!(data.into_bits().to_ne(), 0x8005_0001);
+    /// ```
+    ///
+    /// [`VIRTIO_F_NOTIFICATION_DATA`]: crate::F::NOTIFICATION_DATA
+    pub const fn for_packed(vqn: u16, next_off: u16, next_wrap: bool) -> Self {
+        Self::new()
+            .with_vqn(vqn)
+            .with_next_off(next_off)
+            .with_next_wrap(next_wrap as u8)
+    }
+
+    /// Returns the value the driver writes to notify the device, depending on the negotiated `features`.
+    ///
+    /// Without [`VIRTIO_F_NOTIFICATION_DATA`], this is just `vqn`.
+    /// Otherwise, this is the notification data for a packed virtqueue
+    /// ([`for_packed`](Self::for_packed)) if [`VIRTIO_F_RING_PACKED`] has been negotiated,
+    /// or for a split virtqueue ([`for_split`](Self::for_split)).
+    ///
+    /// For split virtqueues, `next` is the available index and `next_wrap` is ignored.
+    /// For packed virtqueues, `next` is the offset of the next available descriptor and
+    /// `next_wrap` is the wrap counter referring to it.
+    ///
+    /// `vqn` is the virtqueue index or, if [`VIRTIO_F_NOTIF_CONFIG_DATA`] has been negotiated,
+    /// the virtqueue identifier provided by the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// # #[cfg(not(feature = "pci"))]
+    /// # use virtio::mmio::NotificationData;
+    /// # #[cfg(feature = "pci")]
+    /// use virtio::pci::NotificationData;
+    /// use virtio::F;
+    ///
+    /// assert_eq!(NotificationData::value(F::VERSION_1, 2, 0x8003, false), 2);
+    ///
+    /// let features = F::VERSION_1 | F::NOTIFICATION_DATA;
+    /// assert_eq!(NotificationData::value(features, 2, 0x8003, false), 0x8003_0002);
+    ///
+    /// let features = features | F::RING_PACKED;
+    /// assert_eq!(NotificationData::value(features, 1, 5, true), 0x8005_0001);
+    /// ```
+    ///
+    /// [`VIRTIO_F_NOTIFICATION_DATA`]: crate::F::NOTIFICATION_DATA
+    /// [`VIRTIO_F_RING_PACKED`]: crate::F::RING_PACKED
+    /// [`VIRTIO_F_NOTIF_CONFIG_DATA`]: crate::F::NOTIF_CONFIG_DATA
+    pub fn value(features: crate::F, vqn: u16, next: u16, next_wrap: bool) -> u32 {
+        if !features.contains(crate::F::NOTIFICATION_DATA) {
+            return vqn.into();
+        }
+
+        let data = if features.contains(crate::F::RING_PACKED) {
+            Self::for_packed(vqn, next, next_wrap)
+        } else {
+            Self::for_split(vqn, next)
+        };
+        data.into_bits().to_ne()
+    }
+
     /// Available index
     ///
     /// <div class="warning">
@@ -0,0 +1,95 @@
+//! defmt support for structs with endian integers.
+//!
+//! endian-num does not implement `defmt::Format`, so endian integers are formatted through [`Field`] as their native-endian value.
+
+/// Implements `defmt::Format` for a struct, formatting each field through [`Field`].
+///
+/// Fields are copied before formatting, so this also works for packed structs.
+macro_rules! defmt_format_impl {
+    (
+        $Struct:ident {
+            $($field:ident),* $(,)?
+        }
+    ) => {
+        #[cfg(feature = "defmt")]
+        impl ::defmt::Format for $Struct {
+            fn format(&self, f: ::defmt::Formatter<'_>) {
+                ::defmt::write!(f, "{=str} {{", ::core::stringify!($Struct));
+                $(
+                    ::defmt::write!(
+                        f,
+                        " {=str}: {},",
+                        ::core::stringify!($field),
+                        crate::endian_defmt::Field::value({ self.$field }),
+                    );
+                )*
+                ::defmt::write!(f, " }}");
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl crate::endian_defmt::Field for $Struct {
+            type Value = Self;
+
+            fn value(self) -> Self::Value {
+                self
+            }
+        }
+    };
+}
+
+#[cfg(feature = "defmt")]
+pub(crate) use self::field::Field;
+
+#[cfg(feature = "defmt")]
+mod field {
+    use crate::{be128, be16, be32, be64, le128, le16, le32, le64};
+
+    /// A struct field that can be formatted with defmt.
+    pub(crate) trait Field {
+        type Value: defmt::Format;
+
+        fn value(self) -> Self::Value;
+    }
+
+    macro_rules! impl_endian {
+        ($($Int:ty => $Native:ty),* $(,)?) => {
+            $(
+                impl Field for $Int {
+                    type Value = $Native;
+
+                    fn value(self) -> Self::Value {
+                        self.to_ne()
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_endian! {
+        le16 => u16,
+        le32 => u32,
+        le64 => u64,
+        le128 => u128,
+        be16 => u16,
+        be32 => u32,
+        be64 => u64,
+        be128 => u128,
+    }
+
+    impl Field for u8 {
+        type Value = u8;
+
+        fn value(self) -> Self::Value {
+            self
+        }
+    }
+
+    impl<const N: usize> Field for [u8; N] {
+        type Value = [u8; N];
+
+        fn value(self) -> Self::Value {
+            self
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Serde support for endian integers.
+//!
+//! Endian integers are serialized as their native-endian numeric value.
+//! Use this module with `#[serde(with = "crate::endian_serde")]`.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{be128, be16, be32, be64, le128, le16, le32, le64};
+
+pub(crate) trait Native: Copy {
+    type Native: Serialize + DeserializeOwned;
+
+    fn to_native(self) -> Self::Native;
+
+    fn from_native(native: Self::Native) -> Self;
+}
+
+macro_rules! impl_native {
+    ($($Int:ty => $Native:ty),* $(,)?) => {
+        $(
+            impl Native for $Int {
+                type Native = $Native;
+
+                fn to_native(self) -> Self::Native {
+                    self.to_ne()
+                }
+
+                fn from_native(native: Self::Native) -> Self {
+                    Self::from_ne(native)
+                }
+            }
+        )*
+    };
+}
+
+impl_native! {
+    le16 => u16,
+    le32 => u32,
+    le64 => u64,
+    le128 => u128,
+    be16 => u16,
+    be32 => u32,
+    be64 => u64,
+    be128 => u128,
+}
+
+impl Native for u8 {
+    type Native = u8;
+
+    fn to_native(self) -> Self::Native {
+        self
+    }
+
+    fn from_native(native: Self::Native) -> Self {
+        native
+    }
+}
+
+pub(crate) fn serialize<T: Native, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.to_native().serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, T: Native, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::Native::deserialize(deserializer).map(T::from_native)
+}
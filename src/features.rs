@@ -52,6 +52,62 @@ where
             .filter(|requirements| !requirements.is_empty())
             .all(|requirements| self.intersects(requirements))
     }
+
+    /// The number of 32-bit windows that feature bits are transferred through.
+    ///
+    /// Transports select a window by writing its index to a feature select register.
+    const WINDOWS: u32 = (core::mem::size_of::<le128>() / core::mem::size_of::<u32>()) as u32;
+
+    /// Returns the 32-bit window of feature bits selected by `select`.
+    ///
+    /// Window `select` contains the feature bits `32 * select` to `32 * select + 31`.
+    /// Windows beyond [`WINDOWS`](Self::WINDOWS) are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::FeatureBits;
+    ///
+    /// let features = virtio::F::INDIRECT_DESC | virtio::F::VERSION_1;
+    /// assert_eq!(features.window(0), 1 << 28);
+    /// assert_eq!(features.window(1), 1 << 0);
+    /// assert_eq!(features.window(4), 0);
+    ///
+    /// let top = virtio::F::from_bits_retain(virtio::le128::from_ne(1 << 127));
+    /// assert_eq!(top.window(3), 1 << 31);
+    /// ```
+    fn window(&self, select: u32) -> u32 {
+        if select < Self::WINDOWS {
+            (self.bits().to_ne() >> (32 * select)) as u32
+        } else {
+            0
+        }
+    }
+
+    /// Sets the 32-bit window of feature bits selected by `select` to `window`.
+    ///
+    /// Windows beyond [`WINDOWS`](Self::WINDOWS) cannot be represented and are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::FeatureBits;
+    ///
+    /// let mut features = virtio::F::empty();
+    /// features.set_window(0, 1 << 28);
+    /// features.set_window(1, 1 << 0);
+    /// assert_eq!(features, virtio::F::INDIRECT_DESC | virtio::F::VERSION_1);
+    /// ```
+    fn set_window(&mut self, select: u32, window: u32) {
+        if select < Self::WINDOWS {
+            let shift = 32 * select;
+            let bits = self.bits().to_ne() & !(u128::from(u32::MAX) << shift)
+                | u128::from(window) << shift;
+            *self = Self::from_bits_retain(le128::from_ne(bits));
+        }
+    }
 }
 
 endian_bitflags! {
@@ -66,12 +122,14 @@ endian_bitflags! {
         /// Virtqueues / The Virtqueue Descriptor Table / Indirect
         /// Descriptors_ and _Packed Virtqueues / Indirect Flag: Scatter-Gather Support_ _Packed Virtqueues / Indirect Flag: Scatter-Gather Support_.
         #[doc(alias = "VIRTIO_F_INDIRECT_DESC")]
+        #[doc(alias = "VIRTIO_RING_F_INDIRECT_DESC")]
         const INDIRECT_DESC = 1 << 28;
 
         /// This feature enables the _used_event_
         /// and the _avail_event_ fields as described in
         /// _Basic Facilities of a Virtio Device / Virtqueues / Used Buffer Notification Suppression_, _Basic Facilities of a Virtio Device / Virtqueues / The Virtqueue Used Ring_ and _Packed Virtqueues / Driver and Device Event Suppression_.
         #[doc(alias = "VIRTIO_F_EVENT_IDX")]
+        #[doc(alias = "VIRTIO_RING_F_EVENT_IDX")]
         const EVENT_IDX = 1 << 29;
 
         /// This indicates compliance with this
@@ -171,6 +229,12 @@ endian_bitflags! {
         /// See _Basic Facilities of a Virtio Device / Virtqueues / Virtqueue Reset_.
         #[doc(alias = "VIRTIO_F_RING_RESET")]
         const RING_RESET = 1 << 40;
+
+        /// This feature indicates that the device exposes one or more
+        /// administration virtqueues.
+        /// See _Basic Facilities of a Virtio Device / Device groups / Group administration commands_.
+        #[doc(alias = "VIRTIO_F_ADMIN_VQ")]
+        const ADMIN_VQ = 1 << 41;
     }
 }
 
@@ -240,6 +304,9 @@ macro_rules! feature_bits {
 
                 /// Device-independent Bit. See [`virtio::F::RING_RESET`](crate::F::RING_RESET).
                 const RING_RESET = $crate::F::RING_RESET.bits().to_ne();
+
+                /// Device-independent Bit. See [`virtio::F::ADMIN_VQ`](crate::F::ADMIN_VQ).
+                const ADMIN_VQ = $crate::F::ADMIN_VQ.bits().to_ne();
             }
         }
 
@@ -389,6 +456,17 @@ pub mod net {
             #[doc(alias = "VIRTIO_NET_F_CTRL_MAC_ADDR")]
             const CTRL_MAC_ADDR = 1 << 23;
 
+            /// Device supports virtqueue
+            /// notification coalescing.
+            #[doc(alias = "VIRTIO_NET_F_VQ_NOTF_COAL")]
+            const VQ_NOTF_COAL = 1 << 52;
+
+            /// Device supports
+            /// notifications coalescing.
+            #[doc(alias = "VIRTIO_NET_F_NOTF_COAL")]
+            #[doc(alias = "VIRTIO_NET_F_NOTF_COALESCE")]
+            const NOTF_COAL = 1 << 53;
+
             /// Device can receive USO packets. Unlike UFO
             /// (fragmenting the packet) the USO splits large UDP packet
             /// to several segments when each of these smaller packets has UDP header.
@@ -448,6 +526,8 @@ pub mod net {
                     Self::MQ => Self::CTRL_VQ,
                     Self::CTRL_MAC_ADDR => Self::CTRL_VQ,
                     Self::RSC_EXT => Self::HOST_TSO4 | Self::HOST_TSO6,
+                    Self::VQ_NOTF_COAL => Self::CTRL_VQ,
+                    Self::NOTF_COAL => Self::CTRL_VQ,
                     Self::RSS => Self::CTRL_VQ,
                     _ => Self::empty(),
                 };
@@ -459,6 +539,626 @@ pub mod net {
     }
 }
 
+pub mod block {
+    use crate::le128;
+
+    feature_bits! {
+        /// Block Device Feature Bits
+        #[doc(alias = "VIRTIO_BLK_F")]
+        pub struct F: le128 {
+            /// Maximum size of any single segment is
+            /// in `size_max`.
+            #[doc(alias = "VIRTIO_BLK_F_SIZE_MAX")]
+            const SIZE_MAX = 1 << 1;
+
+            /// Maximum number of segments in a
+            /// request is in `seg_max`.
+            #[doc(alias = "VIRTIO_BLK_F_SEG_MAX")]
+            const SEG_MAX = 1 << 2;
+
+            /// Disk-style geometry specified in
+            /// `geometry`.
+            #[doc(alias = "VIRTIO_BLK_F_GEOMETRY")]
+            const GEOMETRY = 1 << 4;
+
+            /// Device is read-only.
+            #[doc(alias = "VIRTIO_BLK_F_RO")]
+            const RO = 1 << 5;
+
+            /// Block size of disk is in `blk_size`.
+            #[doc(alias = "VIRTIO_BLK_F_BLK_SIZE")]
+            const BLK_SIZE = 1 << 6;
+
+            /// Cache flush command support.
+            #[doc(alias = "VIRTIO_BLK_F_FLUSH")]
+            const FLUSH = 1 << 9;
+
+            /// Device exports information on optimal I/O
+            /// alignment.
+            #[doc(alias = "VIRTIO_BLK_F_TOPOLOGY")]
+            const TOPOLOGY = 1 << 10;
+
+            /// Device can toggle its cache between writeback
+            /// and writethrough modes.
+            #[doc(alias = "VIRTIO_BLK_F_CONFIG_WCE")]
+            const CONFIG_WCE = 1 << 11;
+
+            /// Device supports multiqueue.
+            #[doc(alias = "VIRTIO_BLK_F_MQ")]
+            const MQ = 1 << 12;
+
+            /// Device can support discard command, maximum
+            /// discard sectors size in `max_discard_sectors` and maximum discard
+            /// segment number in `max_discard_seg`.
+            #[doc(alias = "VIRTIO_BLK_F_DISCARD")]
+            const DISCARD = 1 << 13;
+
+            /// Device can support write zeroes command,
+            /// maximum write zeroes sectors size in `max_write_zeroes_sectors` and
+            /// maximum write zeroes segment number in `max_write_zeroes_seg`.
+            #[doc(alias = "VIRTIO_BLK_F_WRITE_ZEROES")]
+            const WRITE_ZEROES = 1 << 14;
+
+            /// Device supports providing storage lifetime
+            /// information.
+            #[doc(alias = "VIRTIO_BLK_F_LIFETIME")]
+            const LIFETIME = 1 << 15;
+
+            /// Device supports secure erase command,
+            /// maximum erase sectors count in `max_secure_erase_sectors` and
+            /// maximum erase segment number in `max_secure_erase_seg`.
+            #[doc(alias = "VIRTIO_BLK_F_SECURE_ERASE")]
+            const SECURE_ERASE = 1 << 16;
+
+            /// Device is a Zoned Block Device, that is, a device
+            /// that follows the zoned storage device behavior that is also supported by
+            /// industry standards such as the T10 Zoned Block Command standard (ZBC r05) or
+            /// the NVMe(TM) NVM Express Zoned Namespace Command Set Specification 1.1b (ZNS).
+            #[doc(alias = "VIRTIO_BLK_F_ZONED")]
+            const ZONED = 1 << 17;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod rng {
+    use crate::le128;
+
+    feature_bits! {
+        /// Entropy Device Feature Bits
+        ///
+        /// The entropy device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_RNG_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod gpu {
+    use crate::le128;
+
+    feature_bits! {
+        /// GPU Device Feature Bits
+        #[doc(alias = "VIRTIO_GPU_F")]
+        pub struct F: le128 {
+            /// virgl 3D mode is supported.
+            #[doc(alias = "VIRTIO_GPU_F_VIRGL")]
+            const VIRGL = 1 << 0;
+
+            /// EDID is supported.
+            #[doc(alias = "VIRTIO_GPU_F_EDID")]
+            const EDID = 1 << 1;
+
+            /// assigning resources UUIDs for export
+            /// to other virtio devices is supported.
+            #[doc(alias = "VIRTIO_GPU_F_RESOURCE_UUID")]
+            const RESOURCE_UUID = 1 << 2;
+
+            /// creating and using size-based blob
+            /// resources is supported.
+            #[doc(alias = "VIRTIO_GPU_F_RESOURCE_BLOB")]
+            const RESOURCE_BLOB = 1 << 3;
+
+            /// multiple context types and
+            /// synchronization timelines supported.  Requires VIRTIO_GPU_F_VIRGL.
+            #[doc(alias = "VIRTIO_GPU_F_CONTEXT_INIT")]
+            const CONTEXT_INIT = 1 << 4;
+        }
+    }
+
+    impl crate::FeatureBits for F {
+        fn requirements(&self) -> Self {
+            let mut requirements = Self::empty();
+
+            for feature in self.iter() {
+                let requirement = match feature {
+                    Self::CONTEXT_INIT => Self::VIRGL,
+                    _ => Self::empty(),
+                };
+                requirements.insert(requirement);
+            }
+
+            requirements
+        }
+    }
+}
+
+pub mod input {
+    use crate::le128;
+
+    feature_bits! {
+        /// Input Device Feature Bits
+        ///
+        /// The input device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_INPUT_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod scsi {
+    use crate::le128;
+
+    feature_bits! {
+        /// SCSI Host Device Feature Bits
+        #[doc(alias = "VIRTIO_SCSI_F")]
+        pub struct F: le128 {
+            /// A single request can include both device-readable and device-writable data buffers.
+            #[doc(alias = "VIRTIO_SCSI_F_INOUT")]
+            const INOUT = 1 << 0;
+
+            /// The host SHOULD enable reporting of hot-plug and hot-unplug events for
+            /// LUNs and targets on the SCSI bus. The guest SHOULD handle hot-plug and
+            /// hot-unplug events.
+            #[doc(alias = "VIRTIO_SCSI_F_HOTPLUG")]
+            const HOTPLUG = 1 << 1;
+
+            /// The host will report changes to LUN parameters via a
+            /// VIRTIO_SCSI_T_PARAM_CHANGE event; the guest SHOULD handle them.
+            #[doc(alias = "VIRTIO_SCSI_F_CHANGE")]
+            const CHANGE = 1 << 2;
+
+            /// The extended fields for T10 protection information (DIF/DIX) are included
+            /// in the SCSI request header.
+            #[doc(alias = "VIRTIO_SCSI_F_T10_PI")]
+            const T10_PI = 1 << 3;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod crypto {
+    use crate::le128;
+
+    feature_bits! {
+        /// Crypto Device Feature Bits
+        #[doc(alias = "VIRTIO_CRYPTO_F")]
+        pub struct F: le128 {
+            /// revision 1. Revision 1 has a specific
+            /// request format and other enhancements (which result in some additional requirements).
+            #[doc(alias = "VIRTIO_CRYPTO_F_REVISION_1")]
+            const REVISION_1 = 1 << 0;
+
+            /// stateless mode requests are
+            /// supported by the CIPHER service.
+            #[doc(alias = "VIRTIO_CRYPTO_F_CIPHER_STATELESS_MODE")]
+            const CIPHER_STATELESS_MODE = 1 << 1;
+
+            /// stateless mode requests are
+            /// supported by the HASH service.
+            #[doc(alias = "VIRTIO_CRYPTO_F_HASH_STATELESS_MODE")]
+            const HASH_STATELESS_MODE = 1 << 2;
+
+            /// stateless mode requests are
+            /// supported by the MAC service.
+            #[doc(alias = "VIRTIO_CRYPTO_F_MAC_STATELESS_MODE")]
+            const MAC_STATELESS_MODE = 1 << 3;
+
+            /// stateless mode requests are
+            /// supported by the AEAD service.
+            #[doc(alias = "VIRTIO_CRYPTO_F_AEAD_STATELESS_MODE")]
+            const AEAD_STATELESS_MODE = 1 << 4;
+
+            /// stateless mode requests are
+            /// supported by the AKCIPHER service.
+            #[doc(alias = "VIRTIO_CRYPTO_F_AKCIPHER_STATELESS_MODE")]
+            const AKCIPHER_STATELESS_MODE = 1 << 5;
+        }
+    }
+
+    impl crate::FeatureBits for F {
+        fn requirements(&self) -> Self {
+            let mut requirements = Self::empty();
+
+            for feature in self.iter() {
+                let requirement = match feature {
+                    Self::CIPHER_STATELESS_MODE => Self::REVISION_1,
+                    Self::HASH_STATELESS_MODE => Self::REVISION_1,
+                    Self::MAC_STATELESS_MODE => Self::REVISION_1,
+                    Self::AEAD_STATELESS_MODE => Self::REVISION_1,
+                    Self::AKCIPHER_STATELESS_MODE => Self::REVISION_1,
+                    _ => Self::empty(),
+                };
+                requirements.insert(requirement);
+            }
+
+            requirements
+        }
+    }
+}
+
+pub mod sound {
+    use crate::le128;
+
+    feature_bits! {
+        /// Sound Device Feature Bits
+        ///
+        /// The sound device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_SND_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod iommu {
+    use crate::le128;
+
+    feature_bits! {
+        /// IOMMU Device Feature Bits
+        #[doc(alias = "VIRTIO_IOMMU_F")]
+        pub struct F: le128 {
+            /// Available range of virtual addresses is described in `input_range`.
+            #[doc(alias = "VIRTIO_IOMMU_F_INPUT_RANGE")]
+            const INPUT_RANGE = 1 << 0;
+
+            /// The number of domains supported is described in `domain_range`.
+            #[doc(alias = "VIRTIO_IOMMU_F_DOMAIN_RANGE")]
+            const DOMAIN_RANGE = 1 << 1;
+
+            /// Map and unmap requests are available.
+            #[doc(alias = "VIRTIO_IOMMU_F_MAP_UNMAP")]
+            const MAP_UNMAP = 1 << 2;
+
+            /// When not attached to a domain, endpoints downstream of the IOMMU
+            /// can access the guest-physical address space.
+            #[doc(alias = "VIRTIO_IOMMU_F_BYPASS")]
+            const BYPASS = 1 << 3;
+
+            /// The PROBE request is available.
+            #[doc(alias = "VIRTIO_IOMMU_F_PROBE")]
+            const PROBE = 1 << 4;
+
+            /// The VIRTIO_IOMMU_MAP_F_MMIO flag is available.
+            #[doc(alias = "VIRTIO_IOMMU_F_MMIO")]
+            const MMIO = 1 << 5;
+
+            /// The bypass field of the IOMMU configuration
+            /// controls the behavior of unattached endpoints.
+            #[doc(alias = "VIRTIO_IOMMU_F_BYPASS_CONFIG")]
+            const BYPASS_CONFIG = 1 << 6;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod gpio {
+    use crate::le128;
+
+    feature_bits! {
+        /// GPIO Device Feature Bits
+        #[doc(alias = "VIRTIO_GPIO_F")]
+        pub struct F: le128 {
+            /// The device supports interrupts on GPIO lines.
+            #[doc(alias = "VIRTIO_GPIO_F_IRQ")]
+            const IRQ = 1 << 0;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod i2c {
+    use crate::le128;
+
+    feature_bits! {
+        /// I2C Adapter Device Feature Bits
+        #[doc(alias = "VIRTIO_I2C_F")]
+        pub struct F: le128 {
+            /// The device supports zero-length I2C request and
+            /// VIRTIO_I2C_FLAGS_M_RD flag. It is mandatory to implement this feature.
+            #[doc(alias = "VIRTIO_I2C_F_ZERO_LENGTH_REQUEST")]
+            const ZERO_LENGTH_REQUEST = 1 << 0;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod scmi {
+    use crate::le128;
+
+    feature_bits! {
+        /// SCMI Device Feature Bits
+        #[doc(alias = "VIRTIO_SCMI_F")]
+        pub struct F: le128 {
+            /// Device implements some SCMI
+            /// notifications, or delayed responses.
+            #[doc(alias = "VIRTIO_SCMI_F_P2A_CHANNELS")]
+            const P2A_CHANNELS = 1 << 0;
+
+            /// Device implements any SCMI
+            /// statistics shared memory region.
+            #[doc(alias = "VIRTIO_SCMI_F_SHARED_MEMORY")]
+            const SHARED_MEMORY = 1 << 1;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod pmem {
+    use crate::le128;
+
+    feature_bits! {
+        /// PMEM Device Feature Bits
+        #[doc(alias = "VIRTIO_PMEM_F")]
+        pub struct F: le128 {
+            /// The guest physical address range
+            /// will be indicated as a shared memory region.
+            #[doc(alias = "VIRTIO_PMEM_F_SHMEM_REGION")]
+            const SHMEM_REGION = 1 << 0;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod rpmb {
+    use crate::le128;
+
+    feature_bits! {
+        /// RPMB Device Feature Bits
+        ///
+        /// The RPMB device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_RPMB_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod mem {
+    use crate::le128;
+
+    feature_bits! {
+        /// Memory Device Feature Bits
+        #[doc(alias = "VIRTIO_MEM_F")]
+        pub struct F: le128 {
+            /// The field `node_id` is valid and represents the
+            /// logical numa id of the device memory.
+            #[doc(alias = "VIRTIO_MEM_F_ACPI_PXM")]
+            const ACPI_PXM = 1 << 0;
+
+            /// The driver is not allowed to access unplugged memory.
+            #[doc(alias = "VIRTIO_MEM_F_UNPLUGGED_INACCESSIBLE")]
+            const UNPLUGGED_INACCESSIBLE = 1 << 1;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod balloon {
+    use crate::le128;
+
+    feature_bits! {
+        /// Traditional Memory Balloon Device Feature Bits
+        #[doc(alias = "VIRTIO_BALLOON_F")]
+        pub struct F: le128 {
+            /// Host has to be told before pages from the balloon are used.
+            #[doc(alias = "VIRTIO_BALLOON_F_MUST_TELL_HOST")]
+            const MUST_TELL_HOST = 1 << 0;
+
+            /// A virtqueue for reporting guest memory statistics is present.
+            #[doc(alias = "VIRTIO_BALLOON_F_STATS_VQ")]
+            const STATS_VQ = 1 << 1;
+
+            /// Deflate balloon on guest out of memory condition.
+            #[doc(alias = "VIRTIO_BALLOON_F_DEFLATE_ON_OOM")]
+            const DEFLATE_ON_OOM = 1 << 2;
+
+            /// The device has support for free page hinting.
+            /// A virtqueue for providing hints as to what memory is currently free is present.
+            #[doc(alias = "VIRTIO_BALLOON_F_FREE_PAGE_HINT")]
+            const FREE_PAGE_HINT = 1 << 3;
+
+            /// A hint to the device, that the driver will immediately write
+            /// `poison_val` to pages after deflating them.
+            #[doc(alias = "VIRTIO_BALLOON_F_PAGE_POISON")]
+            const PAGE_POISON = 1 << 4;
+
+            /// The device has support for free page reporting.
+            /// A virtqueue for reporting free guest memory is present.
+            #[doc(alias = "VIRTIO_BALLOON_F_PAGE_REPORTING")]
+            const PAGE_REPORTING = 1 << 5;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod clock {
+    use crate::le128;
+
+    feature_bits! {
+        /// Timer/Clock Device Feature Bits
+        #[doc(alias = "VIRTIO_RTC_F")]
+        pub struct F: le128 {
+            /// The device supports alarms and has an `alarmq`.
+            #[doc(alias = "VIRTIO_RTC_F_ALARM")]
+            const ALARM = 1 << 0;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod can {
+    use crate::le128;
+
+    feature_bits! {
+        /// CAN Device Feature Bits
+        #[doc(alias = "VIRTIO_CAN_F")]
+        pub struct F: le128 {
+            /// The device supports classic CAN frames.
+            #[doc(alias = "VIRTIO_CAN_F_CAN_CLASSIC")]
+            const CAN_CLASSIC = 1 << 0;
+
+            /// The device supports CAN FD frames.
+            #[doc(alias = "VIRTIO_CAN_F_CAN_FD")]
+            const CAN_FD = 1 << 1;
+
+            /// The device marks transmission requests as used only after the frame
+            /// has been sent on the CAN bus.
+            #[doc(alias = "VIRTIO_CAN_F_LATE_TX_ACK")]
+            const LATE_TX_ACK = 1 << 2;
+
+            /// The device supports remote transmission request frames.
+            #[doc(alias = "VIRTIO_CAN_F_RTR_FRAMES")]
+            const RTR_FRAMES = 1 << 3;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod bt {
+    use crate::le128;
+
+    feature_bits! {
+        /// Bluetooth Device Feature Bits
+        #[doc(alias = "VIRTIO_BT_F")]
+        pub struct F: le128 {
+            /// The `vendor` configuration field is valid and vendor-specific HCI commands are supported.
+            #[doc(alias = "VIRTIO_BT_F_VND_HCI")]
+            const VND_HCI = 1 << 0;
+
+            /// The controller supports the Microsoft HCI extension
+            /// and the `msft_opcode` configuration field is valid.
+            #[doc(alias = "VIRTIO_BT_F_MSFT_EXT")]
+            const MSFT_EXT = 1 << 1;
+
+            /// The controller supports the Android Open Source Project HCI extension.
+            #[doc(alias = "VIRTIO_BT_F_AOSP_EXT")]
+            const AOSP_EXT = 1 << 2;
+
+            /// The device uses the aligned configuration layout.
+            #[doc(alias = "VIRTIO_BT_F_CONFIG_V2")]
+            const CONFIG_V2 = 1 << 3;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod video {
+    use crate::le128;
+
+    feature_bits! {
+        /// Video Device Feature Bits
+        #[doc(alias = "VIRTIO_VIDEO_F")]
+        pub struct F: le128 {
+            /// Guest pages can be used as the backing memory of resources.
+            #[doc(alias = "VIRTIO_VIDEO_F_RESOURCE_GUEST_PAGES")]
+            const RESOURCE_GUEST_PAGES = 1 << 0;
+
+            /// The device can use non-contiguous memory for resources.
+            #[doc(alias = "VIRTIO_VIDEO_F_RESOURCE_NON_CONTIG")]
+            const RESOURCE_NON_CONTIG = 1 << 1;
+
+            /// Objects exported by another virtio device can be used as the backing memory of resources.
+            #[doc(alias = "VIRTIO_VIDEO_F_RESOURCE_VIRTIO_OBJECT")]
+            const RESOURCE_VIRTIO_OBJECT = 1 << 2;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod pstore {
+    use crate::le128;
+
+    feature_bits! {
+        /// Pstore Device Feature Bits
+        ///
+        /// The pstore device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_PSTORE_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod nsm {
+    use crate::le128;
+
+    feature_bits! {
+        /// Nitro Secure Module Device Feature Bits
+        ///
+        /// The Nitro Secure Module device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_NSM_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod hwsim {
+    use crate::le128;
+
+    feature_bits! {
+        /// mac80211 hwsim Device Feature Bits
+        ///
+        /// The mac80211 hwsim device currently defines no device-specific feature bits.
+        #[doc(alias = "VIRTIO_HWSIM_F")]
+        pub struct F: le128 {}
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
+pub mod console {
+    use crate::le128;
+
+    feature_bits! {
+        /// Console Device Feature Bits
+        #[doc(alias = "VIRTIO_CONSOLE_F")]
+        pub struct F: le128 {
+            /// Configuration `cols` and `rows` are valid.
+            #[doc(alias = "VIRTIO_CONSOLE_F_SIZE")]
+            const SIZE = 1 << 0;
+
+            /// Device has support for multiple ports;
+            /// `max_nr_ports` is valid and control virtqueues will be used.
+            #[doc(alias = "VIRTIO_CONSOLE_F_MULTIPORT")]
+            const MULTIPORT = 1 << 1;
+
+            /// Device has support for emergency write.
+            /// Configuration field `emerg_wr` is valid.
+            #[doc(alias = "VIRTIO_CONSOLE_F_EMERG_WRITE")]
+            const EMERG_WRITE = 1 << 2;
+        }
+    }
+
+    impl crate::FeatureBits for F {}
+}
+
 pub mod fs {
     use crate::le128;
 
@@ -488,8 +1188,19 @@ pub mod vsock {
             const STREAM = 1 << 0;
 
             /// seqpacket socket type is supported.
+            ///
+            /// This enables [`VIRTIO_VSOCK_TYPE_SEQPACKET`](crate::vsock::Type::Seqpacket)
+            /// and the [`VIRTIO_VSOCK_SEQ_EOM`](crate::vsock::Seq::EOM) and
+            /// [`VIRTIO_VSOCK_SEQ_EOR`](crate::vsock::Seq::EOR) flags.
             #[doc(alias = "VIRTIO_VSOCK_F_SEQPACKET")]
             const SEQPACKET = 1 << 1;
+
+            /// stream socket type is not implied.
+            ///
+            /// If this feature is not negotiated, the stream socket type is
+            /// supported even if [`STREAM`](Self::STREAM) has not been negotiated.
+            #[doc(alias = "VIRTIO_VSOCK_F_NO_IMPLIED_STREAM")]
+            const NO_IMPLIED_STREAM = 1 << 2;
         }
     }
 
@@ -1,4 +1,17 @@
 //! File System Device
+//!
+//! The file system device transports FUSE requests.
+//! Each request consists of a device-readable part (`struct fuse_in_header` followed by
+//! request-specific data) and a device-writable part (`struct fuse_out_header`
+//! followed by response-specific data).
+//! The FUSE structures themselves are defined by the FUSE protocol and are not part of this crate.
+//!
+//! Virtqueue 0 is the hiprio queue for `FUSE_INTERRUPT`, `FUSE_FORGET`, and
+//! `FUSE_BATCH_FORGET` requests, which have no device-writable part.
+//! If [`VIRTIO_FS_F_NOTIFICATION`](F::NOTIFICATION) is negotiated, virtqueue 1 is
+//! the notification queue, whose device-writable buffers receive a `struct fuse_out_header`
+//! followed by the notification data (at least [`notify_buf_size`](ConfigVolatileFieldAccess::notify_buf_size) bytes).
+//! The remaining virtqueues are request queues.
 
 use volatile::access::ReadOnly;
 use volatile_macro::VolatileFieldAccess;
@@ -41,3 +54,9 @@ pub struct Config {
     #[access(ReadOnly)]
     notify_buf_size: le32,
 }
+
+/// The shared memory region ID of the DAX window.
+///
+/// The DAX window allows mapping file contents into the guest physical address space.
+#[doc(alias = "VIRTIO_FS_SHMCAP_ID_CACHE")]
+pub const SHMCAP_ID_CACHE: u8 = 0;
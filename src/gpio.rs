@@ -0,0 +1,237 @@
+//! GPIO Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::gpio::F;
+use crate::{le16, le32};
+
+/// GPIO Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_gpio_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The total number of GPIO lines supported by the device.
+    #[access(ReadOnly)]
+    ngpio: le16,
+
+    #[access(ReadOnly)]
+    padding: [u8; 2],
+
+    /// The size of the gpio-names memory block in bytes,
+    /// which can be fetched by the driver using the
+    /// [`VIRTIO_GPIO_MSG_GET_NAMES`](Msg::GetNames) message.
+    /// The device MUST set this to 0 if it doesn't support names for the GPIO lines.
+    #[access(ReadOnly)]
+    gpio_names_size: le32,
+}
+
+/// Message Type
+#[doc(alias = "VIRTIO_GPIO_MSG")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Msg {
+    #[doc(alias = "VIRTIO_GPIO_MSG_GET_NAMES")]
+    GetNames = 0x0001,
+
+    #[doc(alias = "VIRTIO_GPIO_MSG_GET_DIRECTION")]
+    GetDirection = 0x0002,
+
+    #[doc(alias = "VIRTIO_GPIO_MSG_SET_DIRECTION")]
+    SetDirection = 0x0003,
+
+    #[doc(alias = "VIRTIO_GPIO_MSG_GET_VALUE")]
+    GetValue = 0x0004,
+
+    #[doc(alias = "VIRTIO_GPIO_MSG_SET_VALUE")]
+    SetValue = 0x0005,
+
+    #[doc(alias = "VIRTIO_GPIO_MSG_SET_IRQ_TYPE")]
+    SetIrqType = 0x0006,
+}
+
+/// Message Status
+#[doc(alias = "VIRTIO_GPIO_STATUS")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Status {
+    #[doc(alias = "VIRTIO_GPIO_STATUS_OK")]
+    Ok = 0x0,
+
+    #[doc(alias = "VIRTIO_GPIO_STATUS_ERR")]
+    Err = 0x1,
+}
+
+/// GPIO Line Direction
+#[doc(alias = "VIRTIO_GPIO_DIRECTION")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Direction {
+    #[doc(alias = "VIRTIO_GPIO_DIRECTION_NONE")]
+    None = 0x00,
+
+    #[doc(alias = "VIRTIO_GPIO_DIRECTION_OUT")]
+    Out = 0x01,
+
+    #[doc(alias = "VIRTIO_GPIO_DIRECTION_IN")]
+    In = 0x02,
+}
+
+endian_bitflags! {
+    /// IRQ Trigger Type
+    ///
+    /// An empty set corresponds to `VIRTIO_GPIO_IRQ_TYPE_NONE`.
+    #[doc(alias = "VIRTIO_GPIO_IRQ_TYPE")]
+    pub struct IrqType: le32 {
+        #[doc(alias = "VIRTIO_GPIO_IRQ_TYPE_EDGE_RISING")]
+        const EDGE_RISING = 0x01;
+
+        #[doc(alias = "VIRTIO_GPIO_IRQ_TYPE_EDGE_FALLING")]
+        const EDGE_FALLING = 0x02;
+
+        #[doc(alias = "VIRTIO_GPIO_IRQ_TYPE_EDGE_BOTH")]
+        const EDGE_BOTH = 0x03;
+
+        #[doc(alias = "VIRTIO_GPIO_IRQ_TYPE_LEVEL_HIGH")]
+        const LEVEL_HIGH = 0x04;
+
+        #[doc(alias = "VIRTIO_GPIO_IRQ_TYPE_LEVEL_LOW")]
+        const LEVEL_LOW = 0x08;
+    }
+}
+
+/// Request
+#[doc(alias = "virtio_gpio_request")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Request {
+    /// See [`Msg`].
+    pub type_: le16,
+    pub gpio: le16,
+    /// Used by [`VIRTIO_GPIO_MSG_SET_DIRECTION`](Msg::SetDirection) ([`Direction`]),
+    /// [`VIRTIO_GPIO_MSG_SET_VALUE`](Msg::SetValue), and
+    /// [`VIRTIO_GPIO_MSG_SET_IRQ_TYPE`](Msg::SetIrqType) ([`IrqType`]).
+    pub value: le32,
+}
+
+/// Response
+///
+/// The response to [`VIRTIO_GPIO_MSG_GET_NAMES`](Msg::GetNames) carries
+/// `gpio_names_size` bytes of `value` instead.
+#[doc(alias = "virtio_gpio_response")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Response {
+    /// See [`Status`].
+    pub status: u8,
+    pub value: u8,
+}
+
+/// IRQ Request
+#[doc(alias = "virtio_gpio_irq_request")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct IrqRequest {
+    pub gpio: le16,
+}
+
+/// IRQ Status
+#[doc(alias = "VIRTIO_GPIO_IRQ_STATUS")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum IrqStatus {
+    /// The buffer is returned without an interrupt, for example because it was disabled.
+    #[doc(alias = "VIRTIO_GPIO_IRQ_STATUS_INVALID")]
+    Invalid = 0x0,
+
+    /// An interrupt was detected on the GPIO line.
+    #[doc(alias = "VIRTIO_GPIO_IRQ_STATUS_VALID")]
+    Valid = 0x1,
+}
+
+/// IRQ Response
+#[doc(alias = "virtio_gpio_irq_response")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct IrqResponse {
+    /// See [`IrqStatus`].
+    pub status: u8,
+}
+
+le_bytes_impl! {
+    Request {
+        type_: le16,
+        gpio: le16,
+        value: le32,
+    }
+}
+
+le_bytes_impl! {
+    Response {
+        status: u8,
+        value: u8,
+    }
+}
+
+le_bytes_impl! {
+    IrqRequest {
+        gpio: le16,
+    }
+}
+
+le_bytes_impl! {
+    IrqResponse {
+        status: u8,
+    }
+}
@@ -0,0 +1,693 @@
+//! GPU Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::{ReadOnly, ReadWrite};
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::gpu::F;
+use crate::{le32, le64};
+
+endian_bitflags! {
+    /// GPU Device Events
+    #[doc(alias = "VIRTIO_GPU_EVENT")]
+    pub struct Event: le32 {
+        /// The display configuration has changed.
+        #[doc(alias = "VIRTIO_GPU_EVENT_DISPLAY")]
+        const DISPLAY = 1 << 0;
+    }
+}
+
+/// GPU Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_gpu_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// Signals pending events to the driver.
+    #[access(ReadOnly)]
+    events_read: Event,
+
+    /// Clears pending events in the device.
+    /// Writing a ‘1’ into a bit will clear the corresponding bit in `events_read`.
+    #[access(ReadWrite)]
+    events_clear: Event,
+
+    /// Specifies the maximum number of scanouts supported by the device.
+    #[access(ReadOnly)]
+    num_scanouts: le32,
+
+    /// Specifies the maximum number of capability sets supported by the device.
+    #[access(ReadOnly)]
+    num_capsets: le32,
+}
+
+/// Command Type
+#[doc(alias = "VIRTIO_GPU_CMD")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Cmd {
+    #[doc(alias = "VIRTIO_GPU_CMD_GET_DISPLAY_INFO")]
+    GetDisplayInfo = 0x0100,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_CREATE_2D")]
+    ResourceCreate2d = 0x0101,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_UNREF")]
+    ResourceUnref = 0x0102,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_SET_SCANOUT")]
+    SetScanout = 0x0103,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_FLUSH")]
+    ResourceFlush = 0x0104,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_TRANSFER_TO_HOST_2D")]
+    TransferToHost2d = 0x0105,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_ATTACH_BACKING")]
+    ResourceAttachBacking = 0x0106,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_DETACH_BACKING")]
+    ResourceDetachBacking = 0x0107,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_GET_CAPSET_INFO")]
+    GetCapsetInfo = 0x0108,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_GET_CAPSET")]
+    GetCapset = 0x0109,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_GET_EDID")]
+    GetEdid = 0x010a,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_ASSIGN_UUID")]
+    ResourceAssignUuid = 0x010b,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_CREATE_BLOB")]
+    ResourceCreateBlob = 0x010c,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_SET_SCANOUT_BLOB")]
+    SetScanoutBlob = 0x010d,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_CTX_CREATE")]
+    CtxCreate = 0x0200,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_CTX_DESTROY")]
+    CtxDestroy = 0x0201,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_CTX_ATTACH_RESOURCE")]
+    CtxAttachResource = 0x0202,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_CTX_DETACH_RESOURCE")]
+    CtxDetachResource = 0x0203,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_CREATE_3D")]
+    ResourceCreate3d = 0x0204,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_TRANSFER_TO_HOST_3D")]
+    TransferToHost3d = 0x0205,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_TRANSFER_FROM_HOST_3D")]
+    TransferFromHost3d = 0x0206,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_SUBMIT_3D")]
+    Submit3d = 0x0207,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_MAP_BLOB")]
+    ResourceMapBlob = 0x0208,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_RESOURCE_UNMAP_BLOB")]
+    ResourceUnmapBlob = 0x0209,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_UPDATE_CURSOR")]
+    UpdateCursor = 0x0300,
+
+    #[doc(alias = "VIRTIO_GPU_CMD_MOVE_CURSOR")]
+    MoveCursor = 0x0301,
+}
+
+/// Response Type
+#[doc(alias = "VIRTIO_GPU_RESP")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Resp {
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_NODATA")]
+    OkNodata = 0x1100,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_DISPLAY_INFO")]
+    OkDisplayInfo = 0x1101,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_CAPSET_INFO")]
+    OkCapsetInfo = 0x1102,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_CAPSET")]
+    OkCapset = 0x1103,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_EDID")]
+    OkEdid = 0x1104,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_RESOURCE_UUID")]
+    OkResourceUuid = 0x1105,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_OK_MAP_INFO")]
+    OkMapInfo = 0x1106,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_ERR_UNSPEC")]
+    ErrUnspec = 0x1200,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_ERR_OUT_OF_MEMORY")]
+    ErrOutOfMemory = 0x1201,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_ERR_INVALID_SCANOUT_ID")]
+    ErrInvalidScanoutId = 0x1202,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_ERR_INVALID_RESOURCE_ID")]
+    ErrInvalidResourceId = 0x1203,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_ERR_INVALID_CONTEXT_ID")]
+    ErrInvalidContextId = 0x1204,
+
+    #[doc(alias = "VIRTIO_GPU_RESP_ERR_INVALID_PARAMETER")]
+    ErrInvalidParameter = 0x1205,
+}
+
+endian_bitflags! {
+    /// Control Header Flags
+    #[doc(alias = "VIRTIO_GPU_FLAG")]
+    pub struct Flag: le32 {
+        /// The driver requests a fence.
+        /// The device must complete the command before responding.
+        #[doc(alias = "VIRTIO_GPU_FLAG_FENCE")]
+        const FENCE = 1 << 0;
+
+        /// The `ring_idx` field of the header is valid.
+        ///
+        /// Only if [`VIRTIO_GPU_F_CONTEXT_INIT`](F::CONTEXT_INIT) negotiated.
+        #[doc(alias = "VIRTIO_GPU_FLAG_INFO_RING_IDX")]
+        const INFO_RING_IDX = 1 << 1;
+    }
+}
+
+/// Control Header
+///
+/// All requests and responses on the virtqueues start with this header.
+#[doc(alias = "virtio_gpu_ctrl_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlHdr {
+    /// Specifies the type of the request ([`Cmd`]) or response ([`Resp`]).
+    pub type_: le32,
+    pub flags: Flag,
+    pub fence_id: le64,
+    pub ctx_id: le32,
+    pub ring_idx: u8,
+    pub padding: [u8; 3],
+}
+
+le_bytes_impl! {
+    CtrlHdr {
+        type_: le32,
+        flags: Flag,
+        fence_id: le64,
+        ctx_id: le32,
+        ring_idx: u8,
+        padding: [u8; 3],
+    }
+}
+
+/// Maximum number of scanouts
+#[doc(alias = "VIRTIO_GPU_MAX_SCANOUTS")]
+pub const MAX_SCANOUTS: usize = 16;
+
+/// Rectangle
+#[doc(alias = "virtio_gpu_rect")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Rect {
+    pub x: le32,
+    pub y: le32,
+    pub width: le32,
+    pub height: le32,
+}
+
+/// Display Information of one Scanout
+#[doc(alias = "virtio_gpu_display_one")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct DisplayOne {
+    pub r: Rect,
+    pub enabled: le32,
+    pub flags: le32,
+}
+
+/// Response to [`VIRTIO_GPU_CMD_GET_DISPLAY_INFO`](Cmd::GetDisplayInfo)
+#[doc(alias = "virtio_gpu_resp_display_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespDisplayInfo {
+    pub hdr: CtrlHdr,
+    pub pmodes: [DisplayOne; MAX_SCANOUTS],
+}
+
+/// Request for [`VIRTIO_GPU_CMD_GET_EDID`](Cmd::GetEdid)
+#[doc(alias = "virtio_gpu_get_edid")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct GetEdid {
+    pub hdr: CtrlHdr,
+    pub scanout: le32,
+    pub padding: le32,
+}
+
+/// Response to [`VIRTIO_GPU_CMD_GET_EDID`](Cmd::GetEdid)
+#[doc(alias = "virtio_gpu_resp_edid")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespEdid {
+    pub hdr: CtrlHdr,
+    pub size: le32,
+    pub padding: le32,
+    pub edid: [u8; 1024],
+}
+
+/// Pixel Format
+#[doc(alias = "virtio_gpu_formats")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Format {
+    #[doc(alias = "VIRTIO_GPU_FORMAT_B8G8R8A8_UNORM")]
+    B8G8R8A8Unorm = 1,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_B8G8R8X8_UNORM")]
+    B8G8R8X8Unorm = 2,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_A8R8G8B8_UNORM")]
+    A8R8G8B8Unorm = 3,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_X8R8G8B8_UNORM")]
+    X8R8G8B8Unorm = 4,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_R8G8B8A8_UNORM")]
+    R8G8B8A8Unorm = 67,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_X8B8G8R8_UNORM")]
+    X8B8G8R8Unorm = 68,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_A8B8G8R8_UNORM")]
+    A8B8G8R8Unorm = 121,
+
+    #[doc(alias = "VIRTIO_GPU_FORMAT_R8G8B8X8_UNORM")]
+    R8G8B8X8Unorm = 134,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_RESOURCE_CREATE_2D`](Cmd::ResourceCreate2d)
+#[doc(alias = "virtio_gpu_resource_create_2d")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceCreate2d {
+    pub hdr: CtrlHdr,
+    pub resource_id: le32,
+    /// The pixel format, see [`Format`].
+    pub format: le32,
+    pub width: le32,
+    pub height: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_RESOURCE_UNREF`](Cmd::ResourceUnref)
+#[doc(alias = "virtio_gpu_resource_unref")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceUnref {
+    pub hdr: CtrlHdr,
+    pub resource_id: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_SET_SCANOUT`](Cmd::SetScanout)
+#[doc(alias = "virtio_gpu_set_scanout")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct SetScanout {
+    pub hdr: CtrlHdr,
+    pub r: Rect,
+    pub scanout_id: le32,
+    pub resource_id: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_RESOURCE_FLUSH`](Cmd::ResourceFlush)
+#[doc(alias = "virtio_gpu_resource_flush")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceFlush {
+    pub hdr: CtrlHdr,
+    pub r: Rect,
+    pub resource_id: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_TRANSFER_TO_HOST_2D`](Cmd::TransferToHost2d)
+#[doc(alias = "virtio_gpu_transfer_to_host_2d")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct TransferToHost2d {
+    pub hdr: CtrlHdr,
+    pub r: Rect,
+    pub offset: le64,
+    pub resource_id: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_RESOURCE_ATTACH_BACKING`](Cmd::ResourceAttachBacking)
+///
+/// This request is followed by `nr_entries` [`MemEntry`] structs.
+#[doc(alias = "virtio_gpu_resource_attach_backing")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceAttachBacking {
+    pub hdr: CtrlHdr,
+    pub resource_id: le32,
+    pub nr_entries: le32,
+}
+
+/// Memory Entry
+#[doc(alias = "virtio_gpu_mem_entry")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct MemEntry {
+    pub addr: le64,
+    pub length: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_RESOURCE_DETACH_BACKING`](Cmd::ResourceDetachBacking)
+#[doc(alias = "virtio_gpu_resource_detach_backing")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceDetachBacking {
+    pub hdr: CtrlHdr,
+    pub resource_id: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_GET_CAPSET_INFO`](Cmd::GetCapsetInfo)
+#[doc(alias = "virtio_gpu_get_capset_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct GetCapsetInfo {
+    pub hdr: CtrlHdr,
+    pub capset_index: le32,
+    pub padding: le32,
+}
+
+/// Response to [`VIRTIO_GPU_CMD_GET_CAPSET_INFO`](Cmd::GetCapsetInfo)
+#[doc(alias = "virtio_gpu_resp_capset_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespCapsetInfo {
+    pub hdr: CtrlHdr,
+    pub capset_id: le32,
+    pub capset_max_version: le32,
+    pub capset_max_size: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_GET_CAPSET`](Cmd::GetCapset)
+///
+/// The response is a [`CtrlHdr`] followed by the capability set data.
+#[doc(alias = "virtio_gpu_get_capset")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct GetCapset {
+    pub hdr: CtrlHdr,
+    pub capset_id: le32,
+    pub capset_version: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_CTX_CREATE`](Cmd::CtxCreate)
+#[doc(alias = "virtio_gpu_ctx_create")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtxCreate {
+    pub hdr: CtrlHdr,
+    pub nlen: le32,
+    /// Only if [`VIRTIO_GPU_F_CONTEXT_INIT`](F::CONTEXT_INIT) negotiated.
+    pub context_init: le32,
+    pub debug_name: [u8; 64],
+}
+
+/// Request for [`VIRTIO_GPU_CMD_CTX_ATTACH_RESOURCE`](Cmd::CtxAttachResource) and [`VIRTIO_GPU_CMD_CTX_DETACH_RESOURCE`](Cmd::CtxDetachResource)
+#[doc(alias = "virtio_gpu_ctx_resource")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtxResource {
+    pub hdr: CtrlHdr,
+    pub resource_id: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_SUBMIT_3D`](Cmd::Submit3d)
+///
+/// This request is followed by `size` bytes of command buffer.
+#[doc(alias = "virtio_gpu_cmd_submit")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CmdSubmit {
+    pub hdr: CtrlHdr,
+    pub size: le32,
+    pub padding: le32,
+}
+
+/// Cursor Position
+#[doc(alias = "virtio_gpu_cursor_pos")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CursorPos {
+    pub scanout_id: le32,
+    pub x: le32,
+    pub y: le32,
+    pub padding: le32,
+}
+
+/// Request for [`VIRTIO_GPU_CMD_UPDATE_CURSOR`](Cmd::UpdateCursor) and [`VIRTIO_GPU_CMD_MOVE_CURSOR`](Cmd::MoveCursor)
+///
+/// For [`VIRTIO_GPU_CMD_MOVE_CURSOR`](Cmd::MoveCursor), only `pos` is used.
+#[doc(alias = "virtio_gpu_update_cursor")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct UpdateCursor {
+    pub hdr: CtrlHdr,
+    pub pos: CursorPos,
+    pub resource_id: le32,
+    pub hot_x: le32,
+    pub hot_y: le32,
+    pub padding: le32,
+}
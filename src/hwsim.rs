@@ -0,0 +1,59 @@
+//! mac80211 hwsim Wireless Simulation Device
+//!
+//! <div class="warning">
+//!
+//! This device is not part of a released VIRTIO specification.
+//! The description follows the Linux `mac80211_hwsim` driver.
+//!
+//! </div>
+//!
+//! The hwsim device has two virtqueues:
+//!
+//! - `txq` (0) carries messages from the driver to the device.
+//! - `rxq` (1) carries messages from the device to the driver.
+//!
+//! Each buffer contains a single generic netlink message of the `MAC80211_HWSIM` family,
+//! starting with the netlink and generic netlink headers.
+//! The command of the generic netlink header is a [`Cmd`].
+//! The device has no device-specific feature bits and no device configuration layout.
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+pub use super::features::hwsim::F;
+
+/// Generic Netlink Command
+#[doc(alias = "HWSIM_CMD")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Cmd {
+    #[doc(alias = "HWSIM_CMD_UNSPEC")]
+    Unspec = 0,
+
+    /// Register the receiving end for frames.
+    #[doc(alias = "HWSIM_CMD_REGISTER")]
+    Register = 1,
+
+    /// A frame transmitted by or to a simulated radio.
+    #[doc(alias = "HWSIM_CMD_FRAME")]
+    Frame = 2,
+
+    /// Transmission status of a frame.
+    #[doc(alias = "HWSIM_CMD_TX_INFO_FRAME")]
+    TxInfoFrame = 3,
+
+    #[doc(alias = "HWSIM_CMD_NEW_RADIO")]
+    NewRadio = 4,
+
+    #[doc(alias = "HWSIM_CMD_DEL_RADIO")]
+    DelRadio = 5,
+
+    #[doc(alias = "HWSIM_CMD_GET_RADIO")]
+    GetRadio = 6,
+
+    #[doc(alias = "HWSIM_CMD_ADD_MAC_ADDR")]
+    AddMacAddr = 7,
+
+    #[doc(alias = "HWSIM_CMD_DEL_MAC_ADDR")]
+    DelMacAddr = 8,
+}
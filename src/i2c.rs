@@ -0,0 +1,105 @@
+//! I2C Adapter Device
+//!
+//! The I2C adapter device has a single `requestq` and no device-specific configuration space.
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+pub use super::features::i2c::F;
+use crate::{le16, le32};
+
+endian_bitflags! {
+    /// Request Flags
+    #[doc(alias = "VIRTIO_I2C_FLAGS")]
+    pub struct Flags: le32 {
+        /// The device MUST fail the next request in the same group if this request fails.
+        #[doc(alias = "VIRTIO_I2C_FLAGS_FAIL_NEXT")]
+        const FAIL_NEXT = 1 << 0;
+
+        /// The request is a read request (or a write request otherwise).
+        #[doc(alias = "VIRTIO_I2C_FLAGS_M_RD")]
+        const M_RD = 1 << 1;
+    }
+}
+
+/// Request Header
+///
+/// This is the device-readable header of a request.
+#[doc(alias = "virtio_i2c_out_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct OutHdr {
+    /// The address of the I2C controlled device.
+    pub addr: le16,
+    pub padding: le16,
+    pub flags: Flags,
+}
+
+defmt_format_impl! {
+    OutHdr {
+        addr,
+        padding,
+        flags,
+    }
+}
+
+le_bytes_impl! {
+    OutHdr {
+        addr: le16,
+        padding: le16,
+        flags: Flags,
+    }
+}
+
+/// Request Status
+#[doc(alias = "VIRTIO_I2C_MSG")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Msg {
+    #[doc(alias = "VIRTIO_I2C_MSG_OK")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_I2C_MSG_ERR")]
+    Err = 1,
+}
+
+/// Response Header
+///
+/// This is the device-writable trailer of a request.
+#[doc(alias = "virtio_i2c_in_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct InHdr {
+    /// See [`Msg`].
+    pub status: u8,
+}
+
+defmt_format_impl! {
+    InHdr {
+        status,
+    }
+}
+
+le_bytes_impl! {
+    InHdr {
+        status: u8,
+    }
+}
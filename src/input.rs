@@ -0,0 +1,231 @@
+//! Input Device
+
+use num_enum::{FromPrimitive, IntoPrimitive};
+use volatile::access::{ReadOnly, RestrictAccess};
+use volatile::VolatilePtr;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::input::F;
+use crate::{le16, le32};
+
+/// Configuration Select
+#[doc(alias = "virtio_input_config_select")]
+#[doc(alias = "VIRTIO_INPUT_CFG")]
+#[derive(IntoPrimitive, FromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Cfg {
+    #[doc(alias = "VIRTIO_INPUT_CFG_UNSET")]
+    Unset = 0x00,
+
+    /// `subsel` is zero.
+    /// Returns the name of the device, in `u.string`.
+    #[doc(alias = "VIRTIO_INPUT_CFG_ID_NAME")]
+    IdName = 0x01,
+
+    /// `subsel` is zero.
+    /// Returns the serial number of the device, in `u.string`.
+    #[doc(alias = "VIRTIO_INPUT_CFG_ID_SERIAL")]
+    IdSerial = 0x02,
+
+    /// `subsel` is zero.
+    /// Returns ID information of the device, in `u.ids`.
+    #[doc(alias = "VIRTIO_INPUT_CFG_ID_DEVIDS")]
+    IdDevids = 0x03,
+
+    /// `subsel` is zero.
+    /// Returns input properties of the device, in `u.bitmap`.
+    /// Individual bits in the bitmap correspond to INPUT_PROP_* constants used
+    /// by the underlying evdev implementation.
+    #[doc(alias = "VIRTIO_INPUT_CFG_PROP_BITS")]
+    PropBits = 0x10,
+
+    /// `subsel` specifies the event type using EV_*
+    /// constants in the underlying evdev implementation.  If `size` is non-zero the event
+    /// type is supported and a bitmap of supported event codes is returned in `u.bitmap`.
+    /// Individual bits in the bitmap correspond to implementation-defined input event
+    /// codes, for example keys or pointing device axes.
+    #[doc(alias = "VIRTIO_INPUT_CFG_EV_BITS")]
+    EvBits = 0x11,
+
+    /// `subsel` specifies the absolute axis using ABS_*
+    /// constants in the underlying evdev implementation.
+    /// Information about the axis will be returned in `u.abs`.
+    #[doc(alias = "VIRTIO_INPUT_CFG_ABS_INFO")]
+    AbsInfo = 0x12,
+
+    /// Unknown selector
+    #[num_enum(catch_all)]
+    Unknown(u8),
+}
+
+/// Absolute Axis Information
+#[doc(alias = "virtio_input_absinfo")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct AbsInfo {
+    pub min: le32,
+    pub max: le32,
+    pub fuzz: le32,
+    pub flat: le32,
+    pub res: le32,
+}
+
+/// Device ID Information
+#[doc(alias = "virtio_input_devids")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct DevIds {
+    pub bustype: le16,
+    pub vendor: le16,
+    pub product: le16,
+    pub version: le16,
+}
+
+/// Input Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+/// The payload `u` depends on `select` and `subsel` and can be accessed using [`ConfigUVolatileFieldAccess`].
+#[doc(alias = "virtio_input_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The driver writes the type of information it is interested in here (see [`Cfg`]).
+    select: u8,
+
+    /// The driver writes additional selection information here, depending on `select`.
+    subsel: u8,
+
+    /// The device returns the size of the returned information in `u` here.
+    /// If `size` is zero, the `select` and `subsel` combination is not supported.
+    #[access(ReadOnly)]
+    size: u8,
+
+    #[access(ReadOnly)]
+    reserved: [u8; 5],
+
+    /// The information selected by `select` and `subsel`.
+    #[access(ReadOnly)]
+    u: ConfigU,
+}
+
+/// Input Device Configuration Payload
+///
+/// Use [`ConfigUVolatileFieldAccess`] to work with this union.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union ConfigU {
+    pub string: [u8; 128],
+    pub bitmap: [u8; 128],
+    pub abs: AbsInfo,
+    pub ids: DevIds,
+}
+
+/// Input Device Configuration Payload
+pub trait ConfigUVolatileFieldAccess<'a, A> {
+    /// Only if `select` is [`VIRTIO_INPUT_CFG_ID_NAME`](Cfg::IdName) or [`VIRTIO_INPUT_CFG_ID_SERIAL`](Cfg::IdSerial).
+    fn string(self) -> VolatilePtr<'a, [u8; 128], A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>;
+
+    /// Only if `select` is [`VIRTIO_INPUT_CFG_PROP_BITS`](Cfg::PropBits) or [`VIRTIO_INPUT_CFG_EV_BITS`](Cfg::EvBits).
+    fn bitmap(self) -> VolatilePtr<'a, [u8; 128], A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>;
+
+    /// Only if `select` is [`VIRTIO_INPUT_CFG_ABS_INFO`](Cfg::AbsInfo).
+    fn abs(self) -> VolatilePtr<'a, AbsInfo, A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>;
+
+    /// Only if `select` is [`VIRTIO_INPUT_CFG_ID_DEVIDS`](Cfg::IdDevids).
+    fn ids(self) -> VolatilePtr<'a, DevIds, A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>;
+}
+
+// All fields of a `#[repr(C)]` union are located at offset 0.
+impl<'a, A> ConfigUVolatileFieldAccess<'a, A> for VolatilePtr<'a, ConfigU, A> {
+    fn string(self) -> VolatilePtr<'a, [u8; 128], A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>,
+    {
+        unsafe { self.map(|ptr| ptr.cast::<[u8; 128]>()) }.restrict()
+    }
+
+    fn bitmap(self) -> VolatilePtr<'a, [u8; 128], A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>,
+    {
+        unsafe { self.map(|ptr| ptr.cast::<[u8; 128]>()) }.restrict()
+    }
+
+    fn abs(self) -> VolatilePtr<'a, AbsInfo, A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>,
+    {
+        unsafe { self.map(|ptr| ptr.cast::<AbsInfo>()) }.restrict()
+    }
+
+    fn ids(self) -> VolatilePtr<'a, DevIds, A::Restricted>
+    where
+        A: RestrictAccess<ReadOnly>,
+    {
+        unsafe { self.map(|ptr| ptr.cast::<DevIds>()) }.restrict()
+    }
+}
+
+/// Input Event
+///
+/// The fields follow the underlying evdev implementation.
+#[doc(alias = "virtio_input_event")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Event {
+    pub type_: le16,
+    pub code: le16,
+    pub value: le32,
+}
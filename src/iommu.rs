@@ -0,0 +1,483 @@
+//! IOMMU Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::{ReadOnly, ReadWrite};
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::iommu::F;
+use crate::{le16, le32, le64};
+
+/// 64-bit Range
+#[doc(alias = "virtio_iommu_range_64")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Range64 {
+    pub start: le64,
+    pub end: le64,
+}
+
+/// 32-bit Range
+#[doc(alias = "virtio_iommu_range_32")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Range32 {
+    pub start: le32,
+    pub end: le32,
+}
+
+/// IOMMU Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_iommu_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// Contains the bitmask of all page sizes that can be mapped.
+    /// The least significant bit set defines the page granularity of IOMMU mappings.
+    #[access(ReadOnly)]
+    page_size_mask: le64,
+
+    /// Contains the virtual address range that the IOMMU is able to translate.
+    ///
+    /// Only if [`VIRTIO_IOMMU_F_INPUT_RANGE`](F::INPUT_RANGE) negotiated.
+    #[access(ReadOnly)]
+    input_range: Range64,
+
+    /// Contains the range of supported domain IDs.
+    ///
+    /// Only if [`VIRTIO_IOMMU_F_DOMAIN_RANGE`](F::DOMAIN_RANGE) negotiated.
+    #[access(ReadOnly)]
+    domain_range: Range32,
+
+    /// Contains the maximum size of a probe request's properties.
+    ///
+    /// Only if [`VIRTIO_IOMMU_F_PROBE`](F::PROBE) negotiated.
+    #[access(ReadOnly)]
+    probe_size: le32,
+
+    /// When set to 1, accesses from unattached endpoints are allowed and translated by the IOMMU using the identity function.
+    /// When set to 0, those accesses are forbidden and a fault is reported.
+    ///
+    /// Only if [`VIRTIO_IOMMU_F_BYPASS_CONFIG`](F::BYPASS_CONFIG) negotiated.
+    #[access(ReadWrite)]
+    bypass: u8,
+
+    #[access(ReadOnly)]
+    reserved: [u8; 3],
+}
+
+/// Request Type
+#[doc(alias = "VIRTIO_IOMMU_T")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum T {
+    #[doc(alias = "VIRTIO_IOMMU_T_ATTACH")]
+    Attach = 0x01,
+
+    #[doc(alias = "VIRTIO_IOMMU_T_DETACH")]
+    Detach = 0x02,
+
+    #[doc(alias = "VIRTIO_IOMMU_T_MAP")]
+    Map = 0x03,
+
+    #[doc(alias = "VIRTIO_IOMMU_T_UNMAP")]
+    Unmap = 0x04,
+
+    #[doc(alias = "VIRTIO_IOMMU_T_PROBE")]
+    Probe = 0x05,
+}
+
+/// Request Status
+#[doc(alias = "VIRTIO_IOMMU_S")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum S {
+    /// Success.
+    #[doc(alias = "VIRTIO_IOMMU_S_OK")]
+    Ok = 0x00,
+
+    /// Virtio communication error.
+    #[doc(alias = "VIRTIO_IOMMU_S_IOERR")]
+    IoErr = 0x01,
+
+    /// Unsupported request.
+    #[doc(alias = "VIRTIO_IOMMU_S_UNSUPP")]
+    Unsupp = 0x02,
+
+    /// Internal device error.
+    #[doc(alias = "VIRTIO_IOMMU_S_DEVERR")]
+    DevErr = 0x03,
+
+    /// Invalid parameters.
+    #[doc(alias = "VIRTIO_IOMMU_S_INVAL")]
+    Inval = 0x04,
+
+    /// Out-of-range parameters.
+    #[doc(alias = "VIRTIO_IOMMU_S_RANGE")]
+    Range = 0x05,
+
+    /// Entry not found.
+    #[doc(alias = "VIRTIO_IOMMU_S_NOENT")]
+    NoEnt = 0x06,
+
+    /// Bad address.
+    #[doc(alias = "VIRTIO_IOMMU_S_FAULT")]
+    Fault = 0x07,
+
+    /// Insufficient resources.
+    #[doc(alias = "VIRTIO_IOMMU_S_NOMEM")]
+    NoMem = 0x08,
+}
+
+/// Request Head
+#[doc(alias = "virtio_iommu_req_head")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqHead {
+    /// See [`T`].
+    pub type_: u8,
+    pub reserved: [u8; 3],
+}
+
+le_bytes_impl! {
+    ReqHead {
+        type_: u8,
+        reserved: [u8; 3],
+    }
+}
+
+/// Request Tail
+#[doc(alias = "virtio_iommu_req_tail")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqTail {
+    /// See [`S`].
+    pub status: u8,
+    pub reserved: [u8; 3],
+}
+
+le_bytes_impl! {
+    ReqTail {
+        status: u8,
+        reserved: [u8; 3],
+    }
+}
+
+endian_bitflags! {
+    /// Attach Request Flags
+    #[doc(alias = "VIRTIO_IOMMU_ATTACH_F")]
+    pub struct AttachF: le32 {
+        /// Accesses from the endpoint are allowed and translated using the identity function.
+        #[doc(alias = "VIRTIO_IOMMU_ATTACH_F_BYPASS")]
+        const BYPASS = 1 << 0;
+    }
+}
+
+/// Attach Request
+#[doc(alias = "virtio_iommu_req_attach")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqAttach {
+    pub head: ReqHead,
+    pub domain: le32,
+    pub endpoint: le32,
+    pub flags: AttachF,
+    pub reserved: [u8; 4],
+    pub tail: ReqTail,
+}
+
+/// Detach Request
+#[doc(alias = "virtio_iommu_req_detach")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqDetach {
+    pub head: ReqHead,
+    pub domain: le32,
+    pub endpoint: le32,
+    pub reserved: [u8; 8],
+    pub tail: ReqTail,
+}
+
+endian_bitflags! {
+    /// Map Request Flags
+    #[doc(alias = "VIRTIO_IOMMU_MAP_F")]
+    pub struct MapF: le32 {
+        /// Read access.
+        #[doc(alias = "VIRTIO_IOMMU_MAP_F_READ")]
+        const READ = 1 << 0;
+
+        /// Write access.
+        #[doc(alias = "VIRTIO_IOMMU_MAP_F_WRITE")]
+        const WRITE = 1 << 1;
+
+        /// The mapping is for MMIO rather than memory.
+        #[doc(alias = "VIRTIO_IOMMU_MAP_F_MMIO")]
+        const MMIO = 1 << 2;
+    }
+}
+
+/// Map Request
+#[doc(alias = "virtio_iommu_req_map")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqMap {
+    pub head: ReqHead,
+    pub domain: le32,
+    pub virt_start: le64,
+    pub virt_end: le64,
+    pub phys_start: le64,
+    pub flags: MapF,
+    pub tail: ReqTail,
+}
+
+/// Unmap Request
+#[doc(alias = "virtio_iommu_req_unmap")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqUnmap {
+    pub head: ReqHead,
+    pub domain: le32,
+    pub virt_start: le64,
+    pub virt_end: le64,
+    pub reserved: [u8; 4],
+    pub tail: ReqTail,
+}
+
+/// Probe Request
+///
+/// This is the head of `virtio_iommu_req_probe`.
+/// It is followed by `probe_size` bytes of properties (see [`ProbeProperty`]) and a [`ReqTail`].
+#[doc(alias = "virtio_iommu_req_probe")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqProbe {
+    pub head: ReqHead,
+    pub endpoint: le32,
+    pub reserved: [u8; 64],
+}
+
+/// Probe Property Type
+#[doc(alias = "VIRTIO_IOMMU_PROBE_T")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum ProbeT {
+    /// End of the property list.
+    #[doc(alias = "VIRTIO_IOMMU_PROBE_T_NONE")]
+    None = 0,
+
+    /// Reserved memory region, see [`ProbeResvMem`].
+    #[doc(alias = "VIRTIO_IOMMU_PROBE_T_RESV_MEM")]
+    ResvMem = 1,
+}
+
+/// Probe Property Header
+///
+/// `length` is the size of the property value following this header.
+#[doc(alias = "virtio_iommu_probe_property")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ProbeProperty {
+    /// See [`ProbeT`].
+    pub type_: le16,
+    pub length: le16,
+}
+
+/// Reserved Memory Subtype
+#[doc(alias = "VIRTIO_IOMMU_RESV_MEM_T")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ResvMemT {
+    /// Accesses to virtual addresses in this region have undefined behavior.
+    #[doc(alias = "VIRTIO_IOMMU_RESV_MEM_T_RESERVED")]
+    Reserved = 0,
+
+    /// This region is a doorbell for message-signaled interrupts (MSIs).
+    #[doc(alias = "VIRTIO_IOMMU_RESV_MEM_T_MSI")]
+    Msi = 1,
+}
+
+/// Reserved Memory Property
+#[doc(alias = "virtio_iommu_probe_resv_mem")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ProbeResvMem {
+    /// `.type_` = [`VIRTIO_IOMMU_PROBE_T_RESV_MEM`](ProbeT::ResvMem)
+    pub head: ProbeProperty,
+    /// See [`ResvMemT`].
+    pub subtype: u8,
+    pub reserved: [u8; 3],
+    pub start: le64,
+    pub end: le64,
+}
+
+/// Fault Reason
+#[doc(alias = "VIRTIO_IOMMU_FAULT_R")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum FaultR {
+    /// An internal error happened, or an error that cannot be described with the following reasons.
+    #[doc(alias = "VIRTIO_IOMMU_FAULT_R_UNKNOWN")]
+    Unknown = 0,
+
+    /// The endpoint attempted to access `address` without being attached to a domain.
+    #[doc(alias = "VIRTIO_IOMMU_FAULT_R_DOMAIN")]
+    Domain = 1,
+
+    /// The endpoint attempted to access `address`, which wasn't mapped in the domain or didn't have the correct protection flags.
+    #[doc(alias = "VIRTIO_IOMMU_FAULT_R_MAPPING")]
+    Mapping = 2,
+}
+
+endian_bitflags! {
+    /// Fault Flags
+    #[doc(alias = "VIRTIO_IOMMU_FAULT_F")]
+    pub struct FaultF: le32 {
+        #[doc(alias = "VIRTIO_IOMMU_FAULT_F_READ")]
+        const READ = 1 << 0;
+
+        #[doc(alias = "VIRTIO_IOMMU_FAULT_F_WRITE")]
+        const WRITE = 1 << 1;
+
+        #[doc(alias = "VIRTIO_IOMMU_FAULT_F_EXEC")]
+        const EXEC = 1 << 2;
+
+        /// `address` is valid.
+        #[doc(alias = "VIRTIO_IOMMU_FAULT_F_ADDRESS")]
+        const ADDRESS = 1 << 8;
+    }
+}
+
+/// Fault Report
+#[doc(alias = "virtio_iommu_fault")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Fault {
+    /// See [`FaultR`].
+    pub reason: u8,
+    pub reserved: [u8; 3],
+    pub flags: FaultF,
+    pub endpoint: le32,
+    pub reserved2: [u8; 4],
+    pub address: le64,
+}
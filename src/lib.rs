@@ -29,10 +29,15 @@
 //!
 //! This crate has the following Cargo features:
 //!
-//! - `alloc` enables allocating unsized structs such as [`virtq::Avail`] and [`virtq::Used`] via the [`allocator_api2`] crate.
+//! - `alloc` enables allocating unsized structs such as [`virtq::Avail`], [`virtq::Used`], [`virtq::DescTable`], and [`pvirtq::DescRing`] via the [`allocator_api2`] crate.
+//! - `ccw` enables the [`ccw`] module for Virtio Over Channel I/O.
+//! - `defmt` implements `defmt::Format` for [`Id`], [`DeviceStatus`], [`RingEventFlags`], all flag types, and the small configuration and header structs.
+//!   Endian integers are formatted as their native-endian value.
 //! - `mmio` enables the [`mmio`] module for Virtio Over MMIO.
 //! - `nightly` enables nightly-only functionality.
 //! - `pci` enables the [`pci`] module for Virtio Over PCI via the [`pci_types`] crate.
+//! - `serde` implements `serde::Serialize` and `serde::Deserialize` for all flag types, the device configuration structs of [`net`] and [`block`], and the virtqueue descriptor structs.
+//!   Endian integers and flags are serialized as their native-endian numeric value.
 //! - `zerocopy` derives the following traits for most structs:
 //!   - [`zerocopy::KnownLayout`]
 //!   - [`zerocopy::Immutable`]
@@ -57,31 +62,38 @@
 //! | ---------------- | --------- | -------- |
 //! | PCI Bus          | ✅        | [`pci`]  |
 //! | MMIO             | ✅        | [`mmio`] |
-//! | Channel I/O      | ❌        |          |
+//! | Channel I/O      | ✅        | [`ccw`]  |
 //!
 //! ## Device Types
 //!
-//! | Device Type                       | Available | Module    |
-//! | --------------------------------- | --------- | --------- |
-//! | Network Device                    | ✅        | [`net`]   |
-//! | Block Device                      | ❌        |           |
-//! | Console Device                    | ❌        |           |
-//! | Entropy Device                    | ❌        |           |
-//! | Traditional Memory Balloon Device | ❌        |           |
-//! | SCSI Host Device                  | ❌        |           |
-//! | GPU Device                        | ❌        |           |
-//! | Input Device                      | ❌        |           |
-//! | Crypto Device                     | ❌        |           |
-//! | Socket Device                     | ✅        | [`vsock`] |
-//! | File System Device                | ✅        | [`fs`]    |
-//! | RPMB Device                       | ❌        |           |
-//! | IOMMU Device                      | ❌        |           |
-//! | Sound Device                      | ❌        |           |
-//! | Memory Device                     | ❌        |           |
-//! | I2C Adapter Device                | ❌        |           |
-//! | SCMI Device                       | ❌        |           |
-//! | GPIO Device                       | ❌        |           |
-//! | PMEM Device                       | ❌        |           |
+//! | Device Type                       | Available | Module      |
+//! | --------------------------------- | --------- | ----------- |
+//! | Network Device                    | ✅        | [`net`]     |
+//! | Block Device                      | ✅        | [`block`]   |
+//! | Console Device                    | ✅        | [`console`] |
+//! | Entropy Device                    | ✅        | [`rng`]     |
+//! | Traditional Memory Balloon Device | ✅        | [`balloon`] |
+//! | SCSI Host Device                  | ✅        | [`scsi`]    |
+//! | GPU Device                        | ✅        | [`gpu`]     |
+//! | Input Device                      | ✅        | [`input`]   |
+//! | Crypto Device                     | ✅        | [`crypto`]  |
+//! | Socket Device                     | ✅        | [`vsock`]   |
+//! | File System Device                | ✅        | [`fs`]      |
+//! | RPMB Device                       | ✅        | [`rpmb`]    |
+//! | IOMMU Device                      | ✅        | [`iommu`]   |
+//! | Sound Device                      | ✅        | [`sound`]   |
+//! | Memory Device                     | ✅        | [`mem`]     |
+//! | I2C Adapter Device                | ✅        | [`i2c`]     |
+//! | SCMI Device                       | ✅        | [`scmi`]    |
+//! | GPIO Device                       | ✅        | [`gpio`]    |
+//! | PMEM Device                       | ✅        | [`pmem`]    |
+//! | Timer/Clock Device                | ✅        | [`clock`]   |
+//! | CAN Device                        | ✅        | [`can`]     |
+//! | Bluetooth Device                  | ✅        | [`bt`]      |
+//! | Video Encoder and Decoder Devices | ✅        | [`video`]   |
+//! | pstore Device                     | ✅        | [`pstore`]  |
+//! | Nitro Secure Module               | ✅        | [`nsm`]     |
+//! | mac80211 hwsim Device             | ✅        | [`hwsim`]   |
 
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
@@ -93,20 +105,53 @@ extern crate alloc;
 #[macro_use]
 mod bitflags;
 #[macro_use]
+mod bytes;
+#[macro_use]
+mod endian_defmt;
+#[cfg(feature = "serde")]
+mod endian_serde;
+#[macro_use]
 pub mod volatile;
+pub mod balloon;
+pub mod block;
+pub mod bt;
+pub mod can;
+#[cfg(feature = "ccw")]
+pub mod ccw;
+pub mod clock;
+pub mod console;
+pub mod crypto;
 #[cfg(any(feature = "mmio", feature = "pci"))]
 mod driver_notifications;
 mod features;
 pub mod fs;
+pub mod gpio;
+pub mod gpu;
+pub mod hwsim;
+pub mod i2c;
+pub mod input;
+pub mod iommu;
+pub mod mem;
 #[cfg(feature = "mmio")]
 pub mod mmio;
 pub mod net;
+pub mod nsm;
 #[cfg(feature = "pci")]
 pub mod pci;
+pub mod pmem;
+pub mod pstore;
 pub mod pvirtq;
+pub mod rng;
+pub mod rpmb;
+pub mod scmi;
+pub mod scsi;
+pub mod sound;
+pub mod video;
 pub mod virtq;
 pub mod vsock;
 
+use core::fmt;
+
 pub use endian_num::{be128, be16, be32, be64, le128, le16, le32, le64, Be, Le};
 use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
 
@@ -162,7 +207,112 @@ virtio_bitflags! {
     }
 }
 
+impl DeviceStatus {
+    /// Returns the device status after each step of the device initialization in order.
+    ///
+    /// See _General Initialization And Device Operation / Device Initialization_.
+    /// Between setting [`FEATURES_OK`](Self::FEATURES_OK) and
+    /// [`DRIVER_OK`](Self::DRIVER_OK), the driver has to re-read the device status
+    /// to ensure that `FEATURES_OK` is still set and perform device-specific setup.
+    /// If any step fails, the driver should set [`FAILED`](Self::FAILED).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::DeviceStatus;
+    ///
+    /// let sequence = DeviceStatus::init_sequence();
+    /// assert_eq!(sequence[0], DeviceStatus::ACKNOWLEDGE);
+    /// assert_eq!(
+    ///     sequence[3],
+    ///     DeviceStatus::ACKNOWLEDGE
+    ///         | DeviceStatus::DRIVER
+    ///         | DeviceStatus::FEATURES_OK
+    ///         | DeviceStatus::DRIVER_OK
+    /// );
+    /// ```
+    pub const fn init_sequence() -> &'static [Self] {
+        const ACKNOWLEDGE: DeviceStatus = DeviceStatus::ACKNOWLEDGE;
+        const DRIVER: DeviceStatus = ACKNOWLEDGE.union(DeviceStatus::DRIVER);
+        const FEATURES_OK: DeviceStatus = DRIVER.union(DeviceStatus::FEATURES_OK);
+        const DRIVER_OK: DeviceStatus = FEATURES_OK.union(DeviceStatus::DRIVER_OK);
+
+        &[ACKNOWLEDGE, DRIVER, FEATURES_OK, DRIVER_OK]
+    }
+
+    /// Returns `true` if [`DEVICE_NEEDS_RESET`](Self::DEVICE_NEEDS_RESET) is set.
+    pub const fn needs_reset(self) -> bool {
+        self.contains(Self::DEVICE_NEEDS_RESET)
+    }
+
+    /// Converts a raw device status byte, preserving all bits, including undefined ones.
+    pub const fn from_device(status: u8) -> Self {
+        Self::from_bits_retain(status)
+    }
+
+    /// Checks the device status for error conditions.
+    ///
+    /// Returns [`DeviceError::Failed`] if [`FAILED`](Self::FAILED) is set and
+    /// [`DeviceError::NeedsReset`] if [`DEVICE_NEEDS_RESET`](Self::DEVICE_NEEDS_RESET) is set.
+    /// If both are set, `FAILED` takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::{DeviceError, DeviceStatus};
+    ///
+    /// assert_eq!(DeviceStatus::from_device(0x0f).check_ok(), Ok(()));
+    /// assert_eq!(
+    ///     DeviceStatus::from_device(0x4f).check_ok(),
+    ///     Err(DeviceError::NeedsReset)
+    /// );
+    /// assert_eq!(
+    ///     DeviceStatus::from_device(0xcf).check_ok(),
+    ///     Err(DeviceError::Failed)
+    /// );
+    /// ```
+    pub const fn check_ok(self) -> Result<(), DeviceError> {
+        if self.contains(Self::FAILED) {
+            Err(DeviceError::Failed)
+        } else if self.needs_reset() {
+            Err(DeviceError::NeedsReset)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Device Status Error
+///
+/// See [`DeviceStatus::check_ok`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DeviceError {
+    /// [`FAILED`](DeviceStatus::FAILED) is set.
+    Failed,
+
+    /// [`DEVICE_NEEDS_RESET`](DeviceStatus::DEVICE_NEEDS_RESET) is set.
+    NeedsReset,
+}
+
+impl fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed => f.write_str("device status has FAILED set"),
+            Self::NeedsReset => f.write_str("device needs reset"),
+        }
+    }
+}
+
+impl core::error::Error for DeviceError {}
+
 /// Virtio Device IDs
+///
+/// Some IDs are only reserved: the specification does not define their devices,
+/// so this crate has no device-specific definitions for them.
+/// Such variants are marked as reserved.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(IntoPrimitive, FromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
 #[non_exhaustive]
 #[repr(u8)]
@@ -177,12 +327,16 @@ pub enum Id {
     Block = 2,
 
     /// console
+    ///
+    /// See [`console`].
     Console = 3,
 
     /// entropy source
     Rng = 4,
 
     /// memory ballooning (traditional)
+    ///
+    /// See [`balloon`].
     Balloon = 5,
 
     /// ioMemory
@@ -207,12 +361,18 @@ pub enum Id {
     Caif = 12,
 
     /// memory balloon
+    ///
+    /// This ID is reserved for a memory balloon device that is not specified yet.
+    /// It is not an alias of [`Id::Balloon`], and the [`balloon`] definitions do not apply to it.
+    /// Drivers for the traditional memory balloon device should only match [`Id::Balloon`].
     MemoryBalloon = 13,
 
     /// GPU device
     Gpu = 16,
 
     /// Timer/Clock device
+    ///
+    /// See [`clock`].
     Clock = 17,
 
     /// Input device
@@ -225,9 +385,13 @@ pub enum Id {
     Crypto = 20,
 
     /// Signal Distribution Module
+    ///
+    /// Reserved; not specified.
     SignalDist = 21,
 
     /// pstore device
+    ///
+    /// See [`pstore`].
     Pstore = 22,
 
     /// IOMMU device
@@ -249,42 +413,62 @@ pub enum Id {
     Rpmb = 28,
 
     /// mac80211 hwsim wireless simulation device
+    ///
+    /// See [`hwsim`].
     Mac80211Hwsim = 29,
 
     /// Video encoder device
+    ///
+    /// See [`video`].
     VideoEncoder = 30,
 
     /// Video decoder device
+    ///
+    /// See [`video`].
     VideoDecoder = 31,
 
     /// SCMI device
     Scmi = 32,
 
     /// NitroSecureModule
+    ///
+    /// See [`nsm`].
     NitroSecMod = 33,
 
     /// I2C adapter
     I2cAdapter = 34,
 
     /// Watchdog
+    ///
+    /// Reserved; not specified.
     Watchdog = 35,
 
     /// CAN device
+    ///
+    /// See [`can`].
     Can = 36,
 
     /// Parameter Server
+    ///
+    /// Reserved; not specified.
     ParamServ = 38,
 
     /// Audio policy device
+    ///
+    /// Reserved; not specified.
     AudioPolicy = 39,
 
     /// Bluetooth device
+    ///
+    /// See [`bt`].
     Bt = 40,
 
     /// GPIO device
     Gpio = 41,
 
     /// RDMA device
+    ///
+    /// Reserved; not specified.
     Rdma = 42,
 
     /// Unknown device
@@ -292,8 +476,64 @@ pub enum Id {
     Unknown(u8),
 }
 
+impl Id {
+    /// Returns the minimum number of virtqueues of this device type.
+    ///
+    /// These are the virtqueues that the device has regardless of negotiated features.
+    /// Features such as [`VIRTIO_NET_F_MQ`](net::F::MQ) or
+    /// [`VIRTIO_NET_F_CTRL_VQ`](net::F::CTRL_VQ) add more virtqueues.
+    ///
+    /// Returns `None` for reserved or unknown device IDs whose queue layout is not specified here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::Id;
+    ///
+    /// assert_eq!(Id::Net.min_virtqueues(), Some(2));
+    /// assert_eq!(Id::Block.min_virtqueues(), Some(1));
+    /// assert_eq!(Id::Unknown(0xff).min_virtqueues(), None);
+    /// ```
+    pub const fn min_virtqueues(self) -> Option<u16> {
+        let n = match self {
+            // requestq
+            Self::Block
+            | Self::Rng
+            | Self::NineP
+            | Self::Clock
+            | Self::Pstore
+            | Self::Mem
+            | Self::Pmem
+            | Self::Rpmb
+            | Self::Scmi
+            | Self::NitroSecMod
+            | Self::I2cAdapter
+            | Self::Gpio => 1,
+            // receiveq and transmitq, or similar pairs
+            Self::Net
+            | Self::Console
+            | Self::Balloon
+            | Self::Gpu
+            | Self::Input
+            | Self::Crypto
+            | Self::Iommu
+            | Self::Fs
+            | Self::Mac80211Hwsim
+            | Self::VideoEncoder
+            | Self::VideoDecoder
+            | Self::Bt => 2,
+            Self::Scsi | Self::Vsock | Self::Can => 3,
+            Self::Sound => 4,
+            _ => return None,
+        };
+        Some(n)
+    }
+}
+
 /// Descriptor Ring Change Event Flags
 #[doc(alias = "RING_EVENT_FLAGS")]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
 #[non_exhaustive]
 #[repr(u8)]
@@ -316,6 +556,24 @@ pub enum RingEventFlags {
 }
 
 impl RingEventFlags {
+    /// Extracts the event flags from the raw `flags` field of an event suppression structure.
+    ///
+    /// The event flags are the two least significant bits; the reserved bits are ignored.
+    /// To decode a whole event suppression structure, see [`pvirtq::EventSuppress`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::RingEventFlags;
+    ///
+    /// assert_eq!(RingEventFlags::from_desc_event_flags(0x2), RingEventFlags::Desc);
+    /// assert_eq!(RingEventFlags::from_desc_event_flags(0x8001), RingEventFlags::Disable);
+    /// ```
+    pub const fn from_desc_event_flags(flags: u16) -> Self {
+        Self::from_bits((flags & 0x3) as u8)
+    }
+
     const fn from_bits(bits: u8) -> Self {
         match bits {
             0x0 => Self::Enable,
@@ -352,14 +610,418 @@ pub trait DeviceConfigSpace: Sized {
     /// use volatile::access::ReadOnly;
     /// use volatile::VolatilePtr;
     ///
+    /// # #[cfg(feature = "pci")]
     /// fn read_mac(
     ///     common_cfg: VolatilePtr<'_, virtio::pci::CommonCfg, ReadOnly>,
     ///     net_cfg: VolatilePtr<'_, virtio::net::Config, ReadOnly>,
     /// ) -> [u8; 6] {
     ///     common_cfg.read_config_with(|| net_cfg.mac().read())
     /// }
+    ///
+    /// # #[cfg(feature = "mmio")]
+    /// fn read_mac_mmio(
+    ///     regs: VolatilePtr<'_, virtio::mmio::DeviceRegisters, ReadOnly>,
+    ///     net_cfg: VolatilePtr<'_, virtio::net::Config, ReadOnly>,
+    /// ) -> [u8; 6] {
+    ///     regs.read_config_with(|| net_cfg.mac().read())
+    /// }
+    /// ```
+    ///
+    /// To read several fields consistently, read each field through its accessor inside `f`.
+    /// This accesses each field with its natural width and only touches fields that
+    /// exist for the negotiated features:
+    ///
+    /// ```rust
+    /// # use virtio_spec as virtio;
+    /// use virtio::net::{ConfigVolatileFieldAccess, F};
+    /// use virtio::DeviceConfigSpace;
+    /// use volatile::access::ReadOnly;
+    /// use volatile::VolatilePtr;
+    ///
+    /// # #[cfg(feature = "pci")]
+    /// fn read_mac_and_mtu(
+    ///     common_cfg: VolatilePtr<'_, virtio::pci::CommonCfg, ReadOnly>,
+    ///     net_cfg: VolatilePtr<'_, virtio::net::Config, ReadOnly>,
+    ///     features: F,
+    /// ) -> ([u8; 6], Option<u16>) {
+    ///     common_cfg.read_config_with(|| {
+    ///         let mac = net_cfg.mac().read();
+    ///         let mtu = features
+    ///             .contains(F::MTU)
+    ///             .then(|| net_cfg.mtu().read().to_ne());
+    ///         (mac, mtu)
+    ///     })
+    /// }
     /// ```
     fn read_config_with<F, T>(self, f: F) -> T
     where
         F: FnMut() -> T;
 }
+
+/// Common virtqueue address functionality.
+///
+/// Transports write the 64-bit area addresses of the selected virtqueue as two 32-bit halves,
+/// the low half followed by the high half.
+/// This trait allows setting the addresses independently of the transport.
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use virtio::QueueAddresses;
+///
+/// fn set_addresses(queue: impl QueueAddresses + Copy, desc: u64, driver: u64, device: u64) {
+///     queue.set_queue_desc(desc);
+///     queue.set_queue_driver(driver);
+///     queue.set_queue_device(device);
+/// }
+/// ```
+pub trait QueueAddresses: Sized {
+    /// Sets the physical address of the Descriptor Area of the selected virtqueue.
+    fn set_queue_desc(self, addr: u64);
+
+    /// Sets the physical address of the Driver Area of the selected virtqueue.
+    fn set_queue_driver(self, addr: u64);
+
+    /// Sets the physical address of the Device Area of the selected virtqueue.
+    fn set_queue_device(self, addr: u64);
+}
+
+/// Common transport functionality.
+///
+/// This abstracts the device status, feature and virtqueue registers that
+/// all transports provide so that drivers can be written independently of the transport.
+/// Unlike the transport registers, all values are in native endianness.
+///
+/// Queue-specific operations apply to the queue selected with [`select_queue`](Self::select_queue).
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use virtio::{DeviceStatus, Transport};
+///
+/// fn setup_queue(transport: impl Transport, queue: u16, desc: u64, driver: u64, device: u64) {
+///     transport.select_queue(queue);
+///     let size = transport.read_queue_size_max();
+///     transport.write_queue_size(size);
+///     transport.set_queue_desc(desc);
+///     transport.set_queue_driver(driver);
+///     transport.set_queue_device(device);
+///     transport.enable_queue();
+/// }
+/// ```
+pub trait Transport: QueueAddresses + Copy {
+    /// Reads the device status.
+    fn read_device_status(self) -> DeviceStatus;
+
+    /// Writes the device status.
+    fn write_device_status(self, status: DeviceStatus);
+
+    /// Reads device feature bits `32 * select` to `32 * select + 31`.
+    ///
+    /// See [`FeatureBits::set_window`].
+    fn read_device_features(self, select: u32) -> u32;
+
+    /// Writes driver feature bits `32 * select` to `32 * select + 31`.
+    ///
+    /// See [`FeatureBits::window`].
+    fn write_driver_features(self, select: u32, window: u32);
+
+    /// Reads the configuration atomicity value.
+    fn read_config_generation(self) -> u32;
+
+    /// Selects the virtqueue that the queue-specific operations apply to.
+    fn select_queue(self, queue: u16);
+
+    /// Returns the maximum size of the selected virtqueue.
+    ///
+    /// A value of 0 means that the queue is not available.
+    ///
+    /// For PCI, this is the value of `queue_size` before the driver changes it.
+    fn read_queue_size_max(self) -> u16;
+
+    /// Sets the size of the selected virtqueue.
+    fn write_queue_size(self, size: u16);
+
+    /// Enables the selected virtqueue.
+    fn enable_queue(self);
+
+    /// Returns the notification offset of the selected virtqueue.
+    ///
+    /// For PCI, this is `queue_notify_off`, which has to be multiplied by
+    /// `notify_off_multiplier` of the notification capability.
+    /// This is `None` if all virtqueues are notified through the same register, such as for MMIO.
+    fn read_queue_notify_off(self) -> Option<u16>;
+
+    /// Negotiates the device features.
+    ///
+    /// This reads the features offered by the device, writes the intersection with
+    /// `supported` back as driver features, sets [`FEATURES_OK`](DeviceStatus::FEATURES_OK),
+    /// and re-reads the device status to ensure that `FEATURES_OK` is still set.
+    /// [`VIRTIO_F_VERSION_1`](F::VERSION_1) is always accepted.
+    /// Features whose [`requirements`](FeatureBits::requirements) are not accepted are dropped,
+    /// since the driver must not accept a feature that depends on a feature it does not accept.
+    ///
+    /// The driver has to set [`ACKNOWLEDGE`](DeviceStatus::ACKNOWLEDGE) and
+    /// [`DRIVER`](DeviceStatus::DRIVER) before calling this function.
+    ///
+    /// Returns the negotiated features.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use virtio_spec as virtio;
+    /// use virtio::{NegotiationError, Transport};
+    ///
+    /// fn negotiate_net(transport: impl Transport) -> Result<virtio::net::F, NegotiationError> {
+    ///     let supported = virtio::net::F::MAC | virtio::net::F::MRG_RXBUF;
+    ///     transport.negotiate_features(supported)
+    /// }
+    /// ```
+    fn negotiate_features<T: FeatureBits>(self, supported: T) -> Result<T, NegotiationError>
+    where
+        F: From<T> + AsRef<T> + AsMut<T>,
+    {
+        let mut device_features = T::empty();
+        for select in 0..T::WINDOWS {
+            device_features.set_window(select, self.read_device_features(select));
+        }
+
+        if !device_features.contains(T::from(F::VERSION_1)) {
+            return Err(NegotiationError::Version1NotOffered);
+        }
+
+        let mut driver_features =
+            device_features.intersection(supported.union(T::from(F::VERSION_1)));
+        loop {
+            let unsatisfied = driver_features.iter().find(|feature| {
+                let requirements = feature.requirements();
+                !requirements.is_empty() && !driver_features.intersects(requirements)
+            });
+            match unsatisfied {
+                Some(feature) => driver_features.remove(feature),
+                None => break,
+            }
+        }
+        for select in 0..T::WINDOWS {
+            self.write_driver_features(select, driver_features.window(select));
+        }
+
+        self.write_device_status(self.read_device_status() | DeviceStatus::FEATURES_OK);
+        if !self
+            .read_device_status()
+            .contains(DeviceStatus::FEATURES_OK)
+        {
+            return Err(NegotiationError::FeaturesNotOk);
+        }
+
+        Ok(driver_features)
+    }
+
+    /// Resets the device.
+    ///
+    /// This writes 0 to the device status and reads the device status until it reads back as 0.
+    /// Between two reads, this spins once, for at most `max_spins` spins.
+    /// With `max_spins == 0`, the device status is read only once.
+    /// This is the first step of device initialization.
+    ///
+    /// Returns whether the reset has completed.
+    /// See [`reset_with`](Self::reset_with) for yielding while waiting.
+    fn reset(self, max_spins: u32) -> bool {
+        let mut spins = 0;
+        self.reset_with(|| {
+            if spins == max_spins {
+                return false;
+            }
+            spins += 1;
+            core::hint::spin_loop();
+            true
+        })
+    }
+
+    /// Resets the device, calling `relax` while waiting.
+    ///
+    /// This writes 0 to the device status and polls the device status until it reads back as 0.
+    /// `relax` is called between polls and can yield, sleep, or stop waiting by returning `false`.
+    ///
+    /// Returns whether the reset has completed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use virtio_spec as virtio;
+    /// use virtio::Transport;
+    ///
+    /// fn reset(transport: impl Transport, mut yield_now: impl FnMut()) {
+    ///     transport.reset_with(|| {
+    ///         yield_now();
+    ///         true
+    ///     });
+    /// }
+    /// ```
+    fn reset_with(self, mut relax: impl FnMut() -> bool) -> bool {
+        self.write_device_status(DeviceStatus::empty());
+        while !self.read_device_status().is_empty() {
+            if !relax() {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Feature Negotiation Error
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NegotiationError {
+    /// The device does not offer [`VIRTIO_F_VERSION_1`](F::VERSION_1).
+    Version1NotOffered,
+
+    /// The device did not keep [`FEATURES_OK`](DeviceStatus::FEATURES_OK) set
+    /// and does not support the accepted subset of features.
+    FeaturesNotOk,
+}
+
+impl fmt::Display for NegotiationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version1NotOffered => f.write_str("device does not offer VIRTIO_F_VERSION_1"),
+            Self::FeaturesNotOk => f.write_str("device did not accept the features"),
+        }
+    }
+}
+
+impl core::error::Error for NegotiationError {}
+
+/// Native-endian reads through volatile pointers to endian integers.
+///
+/// This is a shorthand for `ptr.read().to_ne()` and works with the field
+/// accessors of configuration structs, such as `config.mtu().read_ne()`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use core::ptr::NonNull;
+///
+/// use virtio::{le16, VolatileReadNe};
+/// use volatile::VolatilePtr;
+///
+/// let mut mtu = le16::from_ne(1500);
+/// let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut mtu)) };
+/// assert_eq!(ptr.read_ne(), 1500);
+/// ```
+pub trait VolatileReadNe {
+    /// The native integer type.
+    type Native;
+
+    /// Reads the value and converts it to native endianness.
+    fn read_ne(self) -> Self::Native;
+}
+
+macro_rules! impl_volatile_read_ne {
+    ($($Endian:ty => $Native:ty),* $(,)?) => {
+        $(
+            impl<A: ::volatile::access::Readable> VolatileReadNe
+                for ::volatile::VolatilePtr<'_, $Endian, A>
+            {
+                type Native = $Native;
+
+                fn read_ne(self) -> Self::Native {
+                    self.read().to_ne()
+                }
+            }
+        )*
+    };
+}
+
+impl_volatile_read_ne! {
+    le16 => u16,
+    le32 => u32,
+    le64 => u64,
+    le128 => u128,
+    be16 => u16,
+    be32 => u32,
+    be64 => u64,
+    be128 => u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    /// A transport whose device status reads as 0 after `clear_after` reads.
+    #[derive(Clone, Copy)]
+    struct ResetTransport<'a> {
+        reads: &'a Cell<u32>,
+        clear_after: u32,
+    }
+
+    impl QueueAddresses for ResetTransport<'_> {
+        fn set_queue_desc(self, _addr: u64) {}
+
+        fn set_queue_driver(self, _addr: u64) {}
+
+        fn set_queue_device(self, _addr: u64) {}
+    }
+
+    impl Transport for ResetTransport<'_> {
+        fn read_device_status(self) -> DeviceStatus {
+            let reads = self.reads.get();
+            self.reads.set(reads + 1);
+            if reads < self.clear_after {
+                DeviceStatus::DRIVER_OK
+            } else {
+                DeviceStatus::empty()
+            }
+        }
+
+        fn write_device_status(self, _status: DeviceStatus) {}
+
+        fn read_device_features(self, _select: u32) -> u32 {
+            0
+        }
+
+        fn write_driver_features(self, _select: u32, _window: u32) {}
+
+        fn read_config_generation(self) -> u32 {
+            0
+        }
+
+        fn select_queue(self, _queue: u16) {}
+
+        fn read_queue_size_max(self) -> u16 {
+            0
+        }
+
+        fn write_queue_size(self, _size: u16) {}
+
+        fn enable_queue(self) {}
+
+        fn read_queue_notify_off(self) -> Option<u16> {
+            None
+        }
+    }
+
+    fn reset(clear_after: u32, max_spins: u32) -> (bool, u32) {
+        let reads = Cell::new(0);
+        let transport = ResetTransport {
+            reads: &reads,
+            clear_after,
+        };
+        let reset = transport.reset(max_spins);
+        (reset, reads.get())
+    }
+
+    #[test]
+    fn reset_spins() {
+        assert_eq!(reset(0, 0), (true, 1));
+        assert_eq!(reset(1, 0), (false, 1));
+
+        assert_eq!(reset(3, 2), (false, 3));
+        assert_eq!(reset(3, 3), (true, 4));
+        assert_eq!(reset(3, 10), (true, 4));
+    }
+}
@@ -0,0 +1,252 @@
+//! Memory Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::mem::F;
+use crate::{le16, le64};
+
+/// Memory Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_mem_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The size and the alignment in bytes of a memory block. Cannot change.
+    #[access(ReadOnly)]
+    block_size: le64,
+
+    /// Has no meaning without [`VIRTIO_MEM_F_ACPI_PXM`](F::ACPI_PXM) and
+    /// represents the logical numa id of the device memory. Cannot change.
+    #[access(ReadOnly)]
+    node_id: le16,
+
+    #[access(ReadOnly)]
+    padding: [u8; 6],
+
+    /// The start address of the device-managed memory region in guest physical address space. Cannot change.
+    #[access(ReadOnly)]
+    addr: le64,
+
+    /// The size of device-managed memory region in bytes. Cannot change.
+    #[access(ReadOnly)]
+    region_size: le64,
+
+    /// The size of the usable device-managed memory region. Can grow up to `region_size`.
+    /// Can only shrink due to [`VIRTIO_MEM_REQ_UNPLUG_ALL`](Req::UnplugAll) requests.
+    #[access(ReadOnly)]
+    usable_region_size: le64,
+
+    /// The amount of plugged memory in bytes within the usable device-managed memory region.
+    #[access(ReadOnly)]
+    plugged_size: le64,
+
+    /// The requested amount of plugged memory within the usable device-managed memory region.
+    #[access(ReadOnly)]
+    requested_size: le64,
+}
+
+/// Request Type
+#[doc(alias = "VIRTIO_MEM_REQ")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Req {
+    /// Request to plug memory blocks, see [`ReqPlug`].
+    #[doc(alias = "VIRTIO_MEM_REQ_PLUG")]
+    Plug = 0,
+
+    /// Request to unplug memory blocks, see [`ReqUnplug`].
+    #[doc(alias = "VIRTIO_MEM_REQ_UNPLUG")]
+    Unplug = 1,
+
+    /// Request to unplug all memory blocks and shrink the usable device-memory region.
+    #[doc(alias = "VIRTIO_MEM_REQ_UNPLUG_ALL")]
+    UnplugAll = 2,
+
+    /// Request state information, see [`ReqState`].
+    #[doc(alias = "VIRTIO_MEM_REQ_STATE")]
+    State = 3,
+}
+
+/// Request Header
+///
+/// This is the header of `virtio_mem_req`.
+/// Depending on `type_`, it is followed by [`ReqPlug`], [`ReqUnplug`], or [`ReqState`].
+/// For [`VIRTIO_MEM_REQ_UNPLUG_ALL`](Req::UnplugAll), the payload is unused.
+#[doc(alias = "virtio_mem_req")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqHdr {
+    /// See [`Req`].
+    pub type_: le16,
+    pub padding: [le16; 3],
+}
+
+le_bytes_impl! {
+    ReqHdr {
+        type_: le16,
+        padding: [le16; 3],
+    }
+}
+
+/// Plug Request
+#[doc(alias = "virtio_mem_req_plug")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqPlug {
+    pub addr: le64,
+    pub nb_blocks: le16,
+    pub padding: [le16; 3],
+}
+
+/// Unplug Request
+#[doc(alias = "virtio_mem_req_unplug")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqUnplug {
+    pub addr: le64,
+    pub nb_blocks: le16,
+    pub padding: [le16; 3],
+}
+
+/// State Request
+#[doc(alias = "virtio_mem_req_state")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ReqState {
+    pub addr: le64,
+    pub nb_blocks: le16,
+    pub padding: [le16; 3],
+}
+
+/// Response Type
+#[doc(alias = "VIRTIO_MEM_RESP")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Resp {
+    #[doc(alias = "VIRTIO_MEM_RESP_ACK")]
+    Ack = 0,
+
+    #[doc(alias = "VIRTIO_MEM_RESP_NACK")]
+    Nack = 1,
+
+    #[doc(alias = "VIRTIO_MEM_RESP_BUSY")]
+    Busy = 2,
+
+    #[doc(alias = "VIRTIO_MEM_RESP_ERROR")]
+    Error = 3,
+}
+
+/// Response Header
+///
+/// This is the header of `virtio_mem_resp`.
+/// For [`VIRTIO_MEM_REQ_STATE`](Req::State) requests, it is followed by [`RespState`].
+#[doc(alias = "virtio_mem_resp")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespHdr {
+    /// See [`Resp`].
+    pub type_: le16,
+    pub padding: [le16; 3],
+}
+
+le_bytes_impl! {
+    RespHdr {
+        type_: le16,
+        padding: [le16; 3],
+    }
+}
+
+/// Memory Block State
+#[doc(alias = "VIRTIO_MEM_STATE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum State {
+    /// All requested memory blocks are plugged.
+    #[doc(alias = "VIRTIO_MEM_STATE_PLUGGED")]
+    Plugged = 0,
+
+    /// All requested memory blocks are unplugged.
+    #[doc(alias = "VIRTIO_MEM_STATE_UNPLUGGED")]
+    Unplugged = 1,
+
+    /// The requested memory blocks are partially plugged and partially unplugged.
+    #[doc(alias = "VIRTIO_MEM_STATE_MIXED")]
+    Mixed = 2,
+}
+
+/// State Response
+#[doc(alias = "virtio_mem_resp_state")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespState {
+    /// See [`State`].
+    pub state: le16,
+}
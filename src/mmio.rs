@@ -1,13 +1,27 @@
 //! Definitions for Virtio over MMIO.
 
-use core::mem;
+pub mod legacy;
 
-use volatile::access::{ReadOnly, ReadWrite, Readable, RestrictAccess, WriteOnly};
+use core::{fmt, mem};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use volatile::access::{ReadOnly, ReadWrite, Readable, RestrictAccess, Writable, WriteOnly};
 use volatile::VolatilePtr;
 
 pub use crate::driver_notifications::NotificationData;
 use crate::volatile::{OveralignedVolatilePtr, WideVolatilePtr};
-use crate::{le16, le32, DeviceConfigSpace, DeviceStatus, Id};
+use crate::{le16, le32, le64, DeviceConfigSpace, DeviceStatus, Id, QueueAddresses, Transport};
+
+/// The value of the [`MagicValue`] register (a little-endian equivalent of the “virt” string).
+///
+/// [`MagicValue`]: DeviceRegistersVolatileFieldAccess::magic_value
+pub const MAGIC_VALUE: u32 = 0x74726976;
+
+/// The value of the [`Version`] register for non-legacy devices.
+///
+/// [`Version`]: DeviceRegistersVolatileFieldAccess::version
+pub const VERSION: u32 = 0x2;
 
 /// MMIO Device Registers
 ///
@@ -144,6 +158,7 @@ device_register_impl! {
         ///
         /// 0x74726976
         /// (a Little Endian equivalent of the “virt” string).
+        /// See [`MAGIC_VALUE`].
         #[doc(alias = "MagicValue")]
         #[offset(0x000)]
         #[access(ReadOnly)]
@@ -151,7 +166,7 @@ device_register_impl! {
 
         /// Device version number
         ///
-        /// 0x2.
+        /// 0x2. See [`VERSION`].
         ///
         /// <div class="warning">
         ///
@@ -559,6 +574,68 @@ impl_wide_field_access! {
     }
 }
 
+impl Transport for VolatilePtr<'_, DeviceRegisters> {
+    fn read_device_status(self) -> DeviceStatus {
+        self.status().read()
+    }
+
+    fn write_device_status(self, status: DeviceStatus) {
+        self.status().write(status);
+    }
+
+    fn read_device_features(self, select: u32) -> u32 {
+        self.device_features_sel().write(le32::from_ne(select));
+        self.device_features().read().to_ne()
+    }
+
+    fn write_driver_features(self, select: u32, window: u32) {
+        self.driver_features_sel().write(le32::from_ne(select));
+        self.driver_features().write(le32::from_ne(window));
+    }
+
+    fn read_config_generation(self) -> u32 {
+        self.config_generation().read().to_ne()
+    }
+
+    fn select_queue(self, queue: u16) {
+        self.queue_sel().write(le16::from_ne(queue));
+    }
+
+    fn read_queue_size_max(self) -> u16 {
+        self.queue_num_max().read().to_ne()
+    }
+
+    fn write_queue_size(self, size: u16) {
+        self.queue_num().write(le16::from_ne(size));
+    }
+
+    fn enable_queue(self) {
+        self.queue_ready().write(true);
+    }
+
+    fn read_queue_notify_off(self) -> Option<u16> {
+        None
+    }
+}
+
+impl<A> QueueAddresses for VolatilePtr<'_, DeviceRegisters, A>
+where
+    A: RestrictAccess<WriteOnly>,
+    A::Restricted: Writable,
+{
+    fn set_queue_desc(self, addr: u64) {
+        self.queue_desc().write(le64::from_ne(addr));
+    }
+
+    fn set_queue_driver(self, addr: u64) {
+        self.queue_driver().write(le64::from_ne(addr));
+    }
+
+    fn set_queue_device(self, addr: u64) {
+        self.queue_device().write(le64::from_ne(addr));
+    }
+}
+
 impl<'a, A> DeviceConfigSpace for VolatilePtr<'a, DeviceRegisters, A>
 where
     A: RestrictAccess<ReadOnly>,
@@ -580,6 +657,123 @@ where
     }
 }
 
+/// MMIO Device Version
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Version {
+    /// Legacy device (see _Virtio Transport Options / Virtio Over MMIO / Legacy interface_)
+    Legacy = 0x1,
+
+    /// Non-legacy device
+    Modern = 0x2,
+}
+
+/// MMIO Probe Error
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum ProbeError {
+    /// The [`MagicValue`](DeviceRegistersVolatileFieldAccess::magic_value) register does not contain [`MAGIC_VALUE`].
+    BadMagic(u32),
+
+    /// The [`Version`](DeviceRegistersVolatileFieldAccess::version) register contains an unknown version.
+    UnknownVersion(u32),
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic(magic) => write!(f, "bad MMIO magic value {magic:#x}"),
+            Self::UnknownVersion(version) => write!(f, "unknown MMIO version {version:#x}"),
+        }
+    }
+}
+
+impl core::error::Error for ProbeError {}
+
+/// MMIO Device Probing
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use virtio::mmio::{DeviceRegisters, DeviceRegistersVolatileProbe, ProbeError, Version};
+/// use volatile::VolatilePtr;
+///
+/// fn probe(regs: VolatilePtr<'_, DeviceRegisters>) -> Result<Version, ProbeError> {
+///     regs.check_magic()?;
+///     regs.check_version()
+/// }
+/// ```
+pub trait DeviceRegistersVolatileProbe<'a, A> {
+    /// Checks that the [`MagicValue`](DeviceRegistersVolatileFieldAccess::magic_value) register contains [`MAGIC_VALUE`].
+    fn check_magic(self) -> Result<(), ProbeError>
+    where
+        A: RestrictAccess<ReadOnly>,
+        A::Restricted: Readable;
+
+    /// Reads and checks the [`Version`](DeviceRegistersVolatileFieldAccess::version) register.
+    fn check_version(self) -> Result<Version, ProbeError>
+    where
+        A: RestrictAccess<ReadOnly>,
+        A::Restricted: Readable;
+}
+
+impl<'a, A> DeviceRegistersVolatileProbe<'a, A> for VolatilePtr<'a, DeviceRegisters, A> {
+    fn check_magic(self) -> Result<(), ProbeError>
+    where
+        A: RestrictAccess<ReadOnly>,
+        A::Restricted: Readable,
+    {
+        let magic = self.magic_value().read().to_ne();
+        if magic != MAGIC_VALUE {
+            return Err(ProbeError::BadMagic(magic));
+        }
+        Ok(())
+    }
+
+    fn check_version(self) -> Result<Version, ProbeError>
+    where
+        A: RestrictAccess<ReadOnly>,
+        A::Restricted: Readable,
+    {
+        let version = self.version().read().to_ne();
+        Version::try_from(version).map_err(|_| ProbeError::UnknownVersion(version))
+    }
+}
+
+/// Resets the virtqueue with index `queue`.
+///
+/// This selects the queue, writes 1 to [`QueueReset`](DeviceRegistersVolatileFieldAccess::queue_reset),
+/// and polls `QueueReset` until the device presents 0.
+/// `relax` is called between polls and can yield, sleep, or stop waiting by returning `false`.
+///
+/// Returns whether the reset has completed.
+///
+/// Afterwards, the queue is disabled.
+/// This function does not re-enable the queue.
+/// To use the queue again, the caller has to set up the queue and write 1 to
+/// [`QueueReady`](DeviceRegistersVolatileFieldAccess::queue_ready).
+///
+/// This requires [`VIRTIO_F_RING_RESET`] to have been negotiated.
+/// See _Basic Facilities of a Virtio Device / Virtqueues / Virtqueue Reset_.
+///
+/// [`VIRTIO_F_RING_RESET`]: crate::F::RING_RESET
+pub fn reset_queue(
+    regs: VolatilePtr<'_, DeviceRegisters>,
+    queue: u16,
+    mut relax: impl FnMut() -> bool,
+) -> bool {
+    regs.queue_sel().write(le16::from_ne(queue));
+    regs.queue_reset().write(le32::from_ne(1));
+    while regs.queue_reset().read().to_ne() != 0 {
+        if !relax() {
+            return false;
+        }
+    }
+    true
+}
+
 virtio_bitflags! {
     /// Interrupt Status
     pub struct InterruptStatus: u8 {
@@ -594,3 +788,53 @@ virtio_bitflags! {
         const CONFIGURATION_CHANGE_NOTIFICATION = 1 << 1;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::ptr::NonNull;
+
+    use super::*;
+
+    #[test]
+    fn queue_addresses() {
+        let mut regs: DeviceRegisters = unsafe { mem::zeroed() };
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut regs)) };
+
+        ptr.set_queue_device(0x1122_3344_5566_7788);
+        assert_eq!(regs.0[0x0a0 / 4].to_le_bytes(), [0x88, 0x77, 0x66, 0x55]);
+        assert_eq!(regs.0[0x0a4 / 4].to_le_bytes(), [0x44, 0x33, 0x22, 0x11]);
+    }
+
+    #[test]
+    fn transport() {
+        let mut regs: DeviceRegisters = unsafe { mem::zeroed() };
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut regs)) };
+
+        ptr.write_driver_features(1, 0x8000_0001);
+        ptr.select_queue(2);
+        ptr.write_queue_size(256);
+        ptr.enable_queue();
+        assert_eq!(ptr.read_queue_notify_off(), None);
+
+        assert_eq!(regs.0[0x024 / 4].to_ne(), 1);
+        assert_eq!(regs.0[0x020 / 4].to_ne(), 0x8000_0001);
+        assert_eq!(regs.0[0x030 / 4].to_ne(), 2);
+        assert_eq!(regs.0[0x038 / 4].to_ne(), 256);
+        assert_eq!(regs.0[0x044 / 4].to_ne(), 1);
+
+        regs.0[0x070 / 4] = le32::from_ne(DeviceStatus::DRIVER_OK.bits().into());
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut regs)) };
+        assert!(ptr.reset(1));
+        assert_eq!(regs.0[0x070 / 4].to_ne(), 0);
+    }
+
+    #[test]
+    fn queue_reset() {
+        let mut regs: DeviceRegisters = unsafe { mem::zeroed() };
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut regs)) };
+
+        assert!(!reset_queue(ptr, 3, || false));
+        assert_eq!(regs.0[0x030 / 4].to_ne(), 3);
+        assert_eq!(regs.0[0x0c0 / 4].to_ne(), 1);
+    }
+}
@@ -0,0 +1,192 @@
+//! Legacy Interface for Virtio over MMIO.
+//!
+//! Legacy devices report [`Version::Legacy`](super::Version::Legacy) and use page-based queue addressing:
+//! The driver writes the page size to `GuestPageSize` once and then, for each queue,
+//! the alignment of the used ring to `QueueAlign` and the guest-physical
+//! address of the queue divided by the page size to `QueuePFN`.
+//! The legacy queue layout places the used ring at the next `QueueAlign`
+//! boundary after the available ring.
+
+use core::mem;
+
+use volatile::access::{NoAccess, ReadOnly, ReadWrite, WriteOnly};
+use volatile_macro::VolatileFieldAccess;
+
+use crate::le32;
+
+/// Legacy MMIO Device Registers
+///
+/// All registers are 32 bits wide.
+///
+/// Use [`DeviceRegistersVolatileFieldAccess`] to work with this struct.
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct DeviceRegisters {
+    /// Magic Value
+    ///
+    /// See [`MAGIC_VALUE`](super::MAGIC_VALUE).
+    #[doc(alias = "MagicValue")]
+    #[access(ReadOnly)]
+    magic_value: le32,
+
+    /// Device version number
+    ///
+    /// 0x1. See [`Version::Legacy`](super::Version::Legacy).
+    #[doc(alias = "Version")]
+    #[access(ReadOnly)]
+    version: le32,
+
+    /// Virtio Subsystem Device ID
+    ///
+    /// See [`Id`](crate::Id).
+    #[doc(alias = "DeviceID")]
+    #[access(ReadOnly)]
+    device_id: le32,
+
+    /// Virtio Subsystem Vendor ID
+    #[doc(alias = "VendorID")]
+    #[access(ReadOnly)]
+    vendor_id: le32,
+
+    /// Flags representing features the device supports
+    ///
+    /// Reading from this register returns 32 consecutive flag bits,
+    /// selected by `HostFeaturesSel`.
+    #[doc(alias = "HostFeatures")]
+    #[access(ReadOnly)]
+    host_features: le32,
+
+    /// Device (host) features word selection.
+    #[doc(alias = "HostFeaturesSel")]
+    #[access(WriteOnly)]
+    host_features_sel: le32,
+
+    #[access(NoAccess)]
+    _reserved0: [le32; 2],
+
+    /// Flags representing device features understood and activated by the driver
+    ///
+    /// Writing to this register sets 32 consecutive flag bits,
+    /// selected by `GuestFeaturesSel`.
+    #[doc(alias = "GuestFeatures")]
+    #[access(WriteOnly)]
+    guest_features: le32,
+
+    /// Activated (guest) features word selection
+    #[doc(alias = "GuestFeaturesSel")]
+    #[access(WriteOnly)]
+    guest_features_sel: le32,
+
+    /// Guest page size
+    ///
+    /// The driver writes the guest page size in bytes to this register during
+    /// initialization, before any queues are used. This value should be a
+    /// power of 2 and is used by the device to calculate the Guest address
+    /// of the first queue page (see `QueuePFN`).
+    #[doc(alias = "GuestPageSize")]
+    #[access(WriteOnly)]
+    guest_page_size: le32,
+
+    #[access(NoAccess)]
+    _reserved1: le32,
+
+    /// Virtual queue index
+    ///
+    /// Writing to this register selects the virtual queue that the
+    /// following operations on `QueueNumMax`, `QueueNum`, `QueueAlign`
+    /// and `QueuePFN` apply to.
+    #[doc(alias = "QueueSel")]
+    #[access(WriteOnly)]
+    queue_sel: le32,
+
+    /// Maximum virtual queue size
+    ///
+    /// Reading from the register returns the maximum size of the queue
+    /// the device is ready to process or zero (0x0) if the queue is not available.
+    #[doc(alias = "QueueNumMax")]
+    #[access(ReadOnly)]
+    queue_num_max: le32,
+
+    /// Virtual queue size
+    ///
+    /// Writing to this register notifies the device what size of the
+    /// queue the driver will use.
+    #[doc(alias = "QueueNum")]
+    #[access(WriteOnly)]
+    queue_num: le32,
+
+    /// Used Ring alignment in the virtual queue
+    ///
+    /// Writing to this register notifies the device about alignment
+    /// boundary of the Used Ring in bytes. This value should be a power
+    /// of 2.
+    #[doc(alias = "QueueAlign")]
+    #[access(WriteOnly)]
+    queue_align: le32,
+
+    /// Guest physical page number of the virtual queue
+    ///
+    /// Writing to this register notifies the device about location of the
+    /// virtual queue in the Guest’s physical address space. This value
+    /// is the index number of a page starting with the queue
+    /// Descriptor Table. Value zero (0x0) means physical address zero
+    /// (0x00000000) and is illegal. When the driver stops using the
+    /// queue it writes zero (0x0) to this register.
+    /// Reading from this register returns the currently used page
+    /// number of the queue, therefore a value other than zero (0x0)
+    /// means that the queue is in use.
+    #[doc(alias = "QueuePFN")]
+    #[access(ReadWrite)]
+    queue_pfn: le32,
+
+    #[access(NoAccess)]
+    _reserved2: [le32; 3],
+
+    /// Queue notifier
+    ///
+    /// The driver writes the queue index to this register to notify the device.
+    #[doc(alias = "QueueNotify")]
+    #[access(WriteOnly)]
+    queue_notify: le32,
+
+    #[access(NoAccess)]
+    _reserved3: [le32; 3],
+
+    /// Interrupt status
+    ///
+    /// See [`InterruptStatus`](super::InterruptStatus).
+    #[doc(alias = "InterruptStatus")]
+    #[access(ReadOnly)]
+    interrupt_status: le32,
+
+    /// Interrupt acknowledge
+    ///
+    /// See [`InterruptStatus`](super::InterruptStatus).
+    #[doc(alias = "InterruptACK")]
+    #[access(WriteOnly)]
+    interrupt_ack: le32,
+
+    #[access(NoAccess)]
+    _reserved4: [le32; 2],
+
+    /// Device status
+    ///
+    /// See [`DeviceStatus`](crate::DeviceStatus).
+    /// Writing zero (0x0) to this register triggers a device reset.
+    /// This also sets `QueuePFN` to zero (0x0) for all queues in the device.
+    #[doc(alias = "Status")]
+    #[access(ReadWrite)]
+    status: le32,
+
+    #[access(NoAccess)]
+    _reserved5: [le32; 35],
+
+    /// Configuration space
+    ///
+    /// Device-specific configuration space starts at the offset 0x100.
+    #[doc(alias = "Config")]
+    #[access(ReadWrite)]
+    config: [u8; 0],
+}
+
+const _: () = assert!(mem::size_of::<DeviceRegisters>() == 0x100);
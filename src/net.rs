@@ -1,5 +1,7 @@
 //! Network Device
 
+use core::mem;
+
 use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
 use volatile::access::ReadOnly;
 use volatile_macro::VolatileFieldAccess;
@@ -32,23 +34,37 @@ endian_bitflags! {
     )
 )]
 #[derive(VolatileFieldAccess)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Config {
+    /// Only if [`VIRTIO_NET_F_MAC`](F::MAC) negotiated.
     #[access(ReadOnly)]
     mac: [u8; 6],
 
+    /// Only if [`VIRTIO_NET_F_STATUS`](F::STATUS) negotiated.
     #[access(ReadOnly)]
     status: S,
 
+    /// Only if [`VIRTIO_NET_F_MQ`](F::MQ) or [`VIRTIO_NET_F_RSS`](F::RSS) negotiated.
     #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     max_virtqueue_pairs: le16,
 
+    /// Only if [`VIRTIO_NET_F_MTU`](F::MTU) negotiated.
     #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     mtu: le16,
 
+    /// The device speed, in units of 1 MBit per second, 0 to 0x7fffffff, or [`SPEED_UNKNOWN`] for unknown speed.
+    ///
+    /// Only if [`VIRTIO_NET_F_SPEED_DUPLEX`](F::SPEED_DUPLEX) negotiated.
     #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     speed: le32,
 
+    /// See [`Duplex`].
+    ///
+    /// Only if [`VIRTIO_NET_F_SPEED_DUPLEX`](F::SPEED_DUPLEX) negotiated.
     #[access(ReadOnly)]
     duplex: u8,
 
@@ -56,12 +72,86 @@ pub struct Config {
     rss_max_key_size: u8,
 
     #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     rss_max_indirection_table_length: le16,
 
     #[access(ReadOnly)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     supported_hash_types: le32,
 }
 
+impl Config {
+    /// Returns the minimum length of the device configuration for the negotiated `features`.
+    ///
+    /// The transport has to provide at least this many bytes of device-specific configuration
+    /// for all fields that are present with `features` to be accessible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::net::{Config, F};
+    ///
+    /// assert_eq!(Config::min_len(F::empty()), 0);
+    /// assert_eq!(Config::min_len(F::MAC | F::MTU), 12);
+    /// assert_eq!(Config::min_len(F::SPEED_DUPLEX), 17);
+    /// ```
+    pub fn min_len(features: F) -> usize {
+        [
+            (
+                F::MAC,
+                mem::offset_of!(Self, mac) + mem::size_of::<[u8; 6]>(),
+            ),
+            (
+                F::STATUS,
+                mem::offset_of!(Self, status) + mem::size_of::<S>(),
+            ),
+            (
+                F::MQ | F::RSS,
+                mem::offset_of!(Self, max_virtqueue_pairs) + mem::size_of::<le16>(),
+            ),
+            (F::MTU, mem::offset_of!(Self, mtu) + mem::size_of::<le16>()),
+            (
+                F::SPEED_DUPLEX,
+                mem::offset_of!(Self, duplex) + mem::size_of::<u8>(),
+            ),
+            (
+                F::RSS | F::HASH_REPORT,
+                mem::offset_of!(Self, supported_hash_types) + mem::size_of::<le32>(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(feature, _)| features.intersects(*feature))
+        .map(|(_, len)| len)
+        .max()
+        .unwrap_or(0)
+    }
+}
+
+/// Unknown device speed
+#[doc(alias = "VIRTIO_NET_SPEED_UNKNOWN")]
+pub const SPEED_UNKNOWN: u32 = 0xffffffff;
+
+/// Duplex Mode
+#[doc(alias = "VIRTIO_NET_DUPLEX")]
+#[derive(IntoPrimitive, FromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Duplex {
+    #[doc(alias = "VIRTIO_NET_DUPLEX_HALF")]
+    Half = 0x00,
+
+    #[doc(alias = "VIRTIO_NET_DUPLEX_FULL")]
+    Full = 0x01,
+
+    /// Unknown duplex mode
+    ///
+    /// Any values other than [`Half`](Self::Half) and [`Full`](Self::Full) are treated as unknown.
+    #[doc(alias = "VIRTIO_NET_DUPLEX_UNKNOWN")]
+    #[num_enum(default)]
+    Unknown = 0xff,
+}
+
 virtio_bitflags! {
     /// Network Device Header Flags
     #[doc(alias = "VIRTIO_NET_HDR_F")]
@@ -117,17 +207,50 @@ virtio_bitflags! {
 pub struct Hdr {
     pub flags: HdrF,
     pub gso_type: HdrGso,
+    /// A hint to the device as to how much of the header needs to be kept to copy into each packet.
     pub hdr_len: le16,
+    /// Maximum segment size (not including header) if `gso_type` is not [`VIRTIO_NET_HDR_GSO_NONE`](HdrGso::NONE).
     pub gso_size: le16,
+    /// Offset to start checksumming from, if [`VIRTIO_NET_HDR_F_NEEDS_CSUM`](HdrF::NEEDS_CSUM) is set.
     pub csum_start: le16,
+    /// Offset after `csum_start` to place the checksum, if [`VIRTIO_NET_HDR_F_NEEDS_CSUM`](HdrF::NEEDS_CSUM) is set.
     pub csum_offset: le16,
+    /// The number of descriptors the received packet is spread over.
+    ///
+    /// Only valid if [`VIRTIO_NET_F_MRG_RXBUF`](F::MRG_RXBUF) or [`VIRTIO_F_VERSION_1`](crate::F::VERSION_1) negotiated.
+    /// Otherwise, this field is not part of the header.
     pub num_buffers: le16,
 }
 
+defmt_format_impl! {
+    Hdr {
+        flags,
+        gso_type,
+        hdr_len,
+        gso_size,
+        csum_start,
+        csum_offset,
+        num_buffers,
+    }
+}
+
+le_bytes_impl! {
+    Hdr {
+        flags: HdrF,
+        gso_type: HdrGso,
+        hdr_len: le16,
+        gso_size: le16,
+        csum_start: le16,
+        csum_offset: le16,
+        num_buffers: le16,
+    }
+}
+
 /// Network Device Header Hash Report
 ///
 /// Only if VIRTIO_NET_F_HASH_REPORT negotiated
 #[doc(alias = "virtio_net_hdr")]
+#[doc(alias = "virtio_net_hdr_v1_hash")]
 #[cfg_attr(
     feature = "zerocopy",
     derive(
@@ -151,6 +274,7 @@ pub struct HdrHashReport {
 endian_bitflags! {
     /// Hash Type
     #[doc(alias = "VIRTIO_NET_HASH_TYPE")]
+    #[doc(alias = "VIRTIO_NET_RSS_HASH_TYPE")]
     pub struct HashType: le32 {
         #[doc(alias = "VIRTIO_NET_HASH_TYPE_IPv4")]
         const IPV4 = 1 << 0;
@@ -221,6 +345,72 @@ pub enum HashReport {
     Unknown(u16),
 }
 
+/// Receive Side Scaling Configuration Header
+///
+/// This is the head of `virtio_net_rss_config`, the command-specific data of [`VIRTIO_NET_CTRL_MQ_RSS_CONFIG`](ctrl::Mq::RssConfig).
+/// It is followed by `indirection_table_mask + 1` receive queue indices (`le16`) of the indirection table
+/// and by [`RssConfigTrailer`].
+#[doc(alias = "virtio_net_rss_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RssConfigHdr {
+    pub hash_types: HashType,
+    pub indirection_table_mask: le16,
+    pub unclassified_queue: le16,
+}
+
+/// Receive Side Scaling Configuration Trailer
+///
+/// This is the tail of `virtio_net_rss_config`.
+/// It is followed by `hash_key_length` bytes of hash key data.
+#[doc(alias = "virtio_net_rss_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct RssConfigTrailer {
+    pub max_tx_vq: le16,
+    pub hash_key_length: u8,
+}
+
+/// Hash Calculation Configuration
+///
+/// This is the head of `virtio_net_hash_config`, the command-specific data of [`VIRTIO_NET_CTRL_MQ_HASH_CONFIG`](ctrl::Mq::HashConfig).
+/// It is followed by `hash_key_length` bytes of hash key data.
+#[doc(alias = "virtio_net_hash_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct HashConfig {
+    pub hash_types: HashType,
+    pub reserved: [le16; 4],
+    pub hash_key_length: u8,
+}
+
 /// Command class
 #[doc(alias = "VIRTIO_NET_CTRL")]
 #[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
@@ -244,6 +434,211 @@ pub enum Ctrl {
 
     #[doc(alias = "VIRTIO_NET_CTRL_GUEST_OFFLOADS")]
     GuestOffloads = 5,
+
+    #[doc(alias = "VIRTIO_NET_CTRL_NOTF_COAL")]
+    NotfCoal = 6,
+}
+
+/// Control Virtqueue Command Header
+///
+/// Each command on the control virtqueue consists of this header,
+/// followed by command-specific data,
+/// followed by a device-writable [`Ack`].
+///
+/// # Examples
+///
+/// Enabling promiscuous mode:
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::net::{ctrl, Ack, Ctrl, CtrlHdr};
+///
+/// let hdr = CtrlHdr {
+///     class: Ctrl::Rx.into(),
+///     cmd: ctrl::Rx::Promisc.into(),
+/// };
+/// // Device-readable command-specific data: 1 to enable, 0 to disable.
+/// let on: u8 = 1;
+/// # let _ = (hdr, on);
+///
+/// // Device-writable acknowledgement, as written by the device.
+/// let ack: u8 = 0;
+/// assert_eq!(Ack::try_from(ack).unwrap(), Ack::Ok);
+/// ```
+#[doc(alias = "virtio_net_ctrl")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlHdr {
+    /// See [`Ctrl`].
+    pub class: u8,
+    /// See [`ctrl`].
+    pub cmd: u8,
+}
+
+le_bytes_impl! {
+    CtrlHdr {
+        class: u8,
+        cmd: u8,
+    }
+}
+
+/// Control Virtqueue Acknowledgement
+#[doc(alias = "virtio_net_ctrl_ack")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Ack {
+    #[doc(alias = "VIRTIO_NET_OK")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_NET_ERR")]
+    Err = 1,
+}
+
+/// MAC Address Filtering Table
+///
+/// This is the header of `virtio_net_ctrl_mac`.
+/// It is followed by `entries` MAC addresses of 6 bytes each.
+/// [`VIRTIO_NET_CTRL_MAC_TABLE_SET`](ctrl::Mac::TableSet) expects two such tables:
+/// the unicast table followed by the multicast table.
+#[doc(alias = "virtio_net_ctrl_mac")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlMac {
+    pub entries: le32,
+}
+
+/// Automatic Receive Steering Setting
+///
+/// Command-specific data of [`VIRTIO_NET_CTRL_MQ_VQ_PAIRS_SET`](ctrl::Mq::VqPairsSet).
+#[doc(alias = "virtio_net_ctrl_mq")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlMq {
+    /// Between [`CtrlMq::VQ_PAIRS_MIN`] and [`CtrlMq::VQ_PAIRS_MAX`].
+    pub virtqueue_pairs: le16,
+}
+
+impl CtrlMq {
+    #[doc(alias = "VIRTIO_NET_CTRL_MQ_VQ_PAIRS_MIN")]
+    pub const VQ_PAIRS_MIN: u16 = 1;
+
+    #[doc(alias = "VIRTIO_NET_CTRL_MQ_VQ_PAIRS_MAX")]
+    pub const VQ_PAIRS_MAX: u16 = 0x8000;
+}
+
+/// Transmit Notifications Coalescing
+///
+/// Command-specific data of [`VIRTIO_NET_CTRL_NOTF_COAL_TX_SET`](ctrl::NotfCoal::TxSet).
+#[doc(alias = "virtio_net_ctrl_coal_tx")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CoalTx {
+    /// Maximum number of packets to send before a TX notification.
+    pub tx_max_packets: le32,
+    /// Maximum number of microseconds to delay a TX notification.
+    pub tx_usecs: le32,
+}
+
+/// Receive Notifications Coalescing
+///
+/// Command-specific data of [`VIRTIO_NET_CTRL_NOTF_COAL_RX_SET`](ctrl::NotfCoal::RxSet).
+#[doc(alias = "virtio_net_ctrl_coal_rx")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CoalRx {
+    /// Maximum number of packets to receive before a RX notification.
+    pub rx_max_packets: le32,
+    /// Maximum number of microseconds to delay a RX notification.
+    pub rx_usecs: le32,
+}
+
+/// Notifications Coalescing Parameters
+#[doc(alias = "virtio_net_ctrl_coal")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Coal {
+    /// Maximum number of packets to receive or send before a notification.
+    pub max_packets: le32,
+    /// Maximum number of microseconds to delay a notification.
+    pub max_usecs: le32,
+}
+
+/// Virtqueue Notifications Coalescing
+///
+/// Command-specific data of [`VIRTIO_NET_CTRL_NOTF_COAL_VQ_SET`](ctrl::NotfCoal::VqSet) and
+/// [`VIRTIO_NET_CTRL_NOTF_COAL_VQ_GET`](ctrl::NotfCoal::VqGet).
+/// For [`VIRTIO_NET_CTRL_NOTF_COAL_VQ_GET`](ctrl::NotfCoal::VqGet), `vq_index` is device-readable and `coal` is device-writable.
+#[doc(alias = "virtio_net_ctrl_coal_vq")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CoalVq {
+    pub vq_index: le16,
+    pub reserved: le16,
+    pub coal: Coal,
 }
 
 /// Commands
@@ -339,4 +734,27 @@ pub mod ctrl {
         #[doc(alias = "VIRTIO_NET_CTRL_GUEST_OFFLOADS_SET")]
         Set = 0,
     }
+
+    /// Notifications Coalescing commands
+    #[doc(alias = "VIRTIO_NET_CTRL_NOTF_COAL")]
+    #[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+    #[non_exhaustive]
+    #[repr(u8)]
+    pub enum NotfCoal {
+        /// See [`CoalTx`](super::CoalTx).
+        #[doc(alias = "VIRTIO_NET_CTRL_NOTF_COAL_TX_SET")]
+        TxSet = 0,
+
+        /// See [`CoalRx`](super::CoalRx).
+        #[doc(alias = "VIRTIO_NET_CTRL_NOTF_COAL_RX_SET")]
+        RxSet = 1,
+
+        /// See [`CoalVq`](super::CoalVq).
+        #[doc(alias = "VIRTIO_NET_CTRL_NOTF_COAL_VQ_SET")]
+        VqSet = 2,
+
+        /// See [`CoalVq`](super::CoalVq).
+        #[doc(alias = "VIRTIO_NET_CTRL_NOTF_COAL_VQ_GET")]
+        VqGet = 3,
+    }
 }
@@ -0,0 +1,15 @@
+//! Nitro Secure Module Device
+//!
+//! <div class="warning">
+//!
+//! This device is not part of a released VIRTIO specification.
+//! The description follows the AWS Nitro Enclaves implementation.
+//!
+//! </div>
+//!
+//! The Nitro Secure Module device has a single virtqueue, `requestq`.
+//! Each request consists of a device-readable buffer containing a CBOR-encoded request,
+//! followed by a device-writable buffer that the device fills with the CBOR-encoded response.
+//! The device has no device-specific feature bits and no device configuration layout.
+
+pub use super::features::nsm::F;
@@ -1,17 +1,64 @@
 //! Definitions for Virtio over PCI bus.
 
-use core::mem;
+pub mod legacy;
+
+use core::{fmt, mem};
 
 use num_enum::{FromPrimitive, IntoPrimitive};
-use pci_types::capability::PciCapabilityAddress;
-use pci_types::ConfigRegionAccess;
-use volatile::access::{ReadOnly, ReadWrite, Readable, RestrictAccess};
+use pci_types::capability::{CapabilityIterator, PciCapability, PciCapabilityAddress};
+use pci_types::{ConfigRegionAccess, EndpointHeader, PciAddress};
+use volatile::access::{ReadOnly, ReadWrite, Readable, RestrictAccess, Writable};
 use volatile::VolatilePtr;
 use volatile_macro::VolatileFieldAccess;
 
 pub use crate::driver_notifications::NotificationData;
 use crate::volatile::WideVolatilePtr;
-use crate::{le16, le32, le64, DeviceConfigSpace, DeviceStatus, Le};
+use crate::{le16, le32, le64, DeviceConfigSpace, DeviceStatus, Id, Le, QueueAddresses, Transport};
+
+/// The PCI Vendor ID of virtio devices.
+pub const VENDOR_ID: u16 = 0x1af4;
+
+impl Id {
+    /// Returns the virtio device ID for a PCI Device ID.
+    ///
+    /// Non-transitional devices use the PCI Device ID 0x1040 plus the virtio device ID.
+    /// Transitional devices use a PCI Device ID in the range 0x1000 to 0x103f.
+    /// This returns `None` for PCI Device IDs of transitional devices that do not
+    /// encode the device type, in which case the PCI Subsystem Device ID is the virtio device ID.
+    /// This also returns `None` for 0x1040, since the virtio device ID 0 is reserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::Id;
+    ///
+    /// assert_eq!(Id::from_pci_device_id(0x1041), Some(Id::Net));
+    /// assert_eq!(Id::from_pci_device_id(0x1001), Some(Id::Block));
+    /// assert_eq!(Id::from_pci_device_id(0x1010), None);
+    /// assert_eq!(Id::from_pci_device_id(0x1040), None);
+    /// assert_eq!(Id::Net.to_modern_pci_device_id(), 0x1041);
+    /// ```
+    pub fn from_pci_device_id(pci_device_id: u16) -> Option<Self> {
+        let id = match pci_device_id {
+            0x1000 => Self::Net,
+            0x1001 => Self::Block,
+            0x1002 => Self::Balloon,
+            0x1003 => Self::Console,
+            0x1004 => Self::Scsi,
+            0x1005 => Self::Rng,
+            0x1009 => Self::NineP,
+            0x1041..=0x107f => Self::from((pci_device_id - 0x1040) as u8),
+            _ => return None,
+        };
+        Some(id)
+    }
+
+    /// Returns the PCI Device ID of a non-transitional device with this device ID.
+    pub fn to_modern_pci_device_id(self) -> u16 {
+        0x1040 + u16::from(u8::from(self))
+    }
+}
 
 /// PCI Capability
 ///
@@ -156,10 +203,26 @@ impl Cap {
 #[repr(C)]
 pub struct Cap64 {
     pub cap: Cap,
+
+    /// High 32 bits of the offset within bar.
     pub offset_hi: le32,
+
+    /// High 32 bits of the length of the structure, in bytes.
     pub length_hi: le32,
 }
 
+impl Cap64 {
+    /// Returns the full 64-bit offset within the bar.
+    pub fn offset(&self) -> le64 {
+        le64::from([self.cap.offset, self.offset_hi])
+    }
+
+    /// Returns the full 64-bit length of the structure, in bytes.
+    pub fn length(&self) -> le64 {
+        le64::from([self.cap.length, self.length_hi])
+    }
+}
+
 /// PCI Notify Capability
 #[doc(alias = "virtio_pci_notify_cap")]
 #[cfg_attr(
@@ -198,6 +261,261 @@ pub struct CfgCap {
     pub pci_cfg_data: [u8; 4],
 }
 
+/// PCI Configuration Access
+///
+/// This accesses BAR regions through a [`VIRTIO_PCI_CAP_PCI_CFG`](CapCfgType::Pci) capability ([`CfgCap`])
+/// instead of mapping the BAR.
+/// Each access first selects the BAR, offset, and length via `cap.bar`, `cap.offset`, and `cap.length`,
+/// and then accesses `pci_cfg_data`.
+///
+/// `offset` has to be aligned to the size of the access.
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use pci_types::{ConfigRegionAccess, EndpointHeader};
+/// use virtio::pci::CfgCapAccess;
+///
+/// fn read_device_status(header: &EndpointHeader, access: &impl ConfigRegionAccess, bar: u8, common_cfg_offset: u32) -> Option<u8> {
+///     let cfg_access = CfgCapAccess::find(header, access)?;
+///     Some(cfg_access.read_u8(bar, common_cfg_offset + 0x14))
+/// }
+/// ```
+pub struct CfgCapAccess<'a, T: ConfigRegionAccess> {
+    addr: PciCapabilityAddress,
+    access: &'a T,
+}
+
+impl<'a, T: ConfigRegionAccess> CfgCapAccess<'a, T> {
+    const BAR_OFFSET: u16 = mem::offset_of!(Cap, bar) as u16;
+    const OFFSET_OFFSET: u16 = mem::offset_of!(Cap, offset) as u16;
+    const LENGTH_OFFSET: u16 = mem::offset_of!(Cap, length) as u16;
+    const DATA_OFFSET: u16 = mem::offset_of!(CfgCap, pci_cfg_data) as u16;
+
+    /// Creates a new configuration access for the capability at `addr`.
+    ///
+    /// Returns `None` if the capability is not a well-formed
+    /// [`VIRTIO_PCI_CAP_PCI_CFG`](CapCfgType::Pci) capability.
+    pub fn new(addr: PciCapabilityAddress, access: &'a T) -> Option<Self> {
+        let cap = Cap::read(addr, access)?;
+
+        if CapCfgType::from(cap.cfg_type) != CapCfgType::Pci {
+            return None;
+        }
+
+        if usize::from(cap.cap_len) < mem::size_of::<CfgCap>() {
+            return None;
+        }
+
+        Some(Self { addr, access })
+    }
+
+    /// Finds the [`VIRTIO_PCI_CAP_PCI_CFG`](CapCfgType::Pci) capability of the device with the given header.
+    pub fn find(header: &EndpointHeader, access: &'a T) -> Option<Self> {
+        header
+            .capabilities(access)
+            .find_map(|capability| match capability {
+                PciCapability::Vendor(addr) => Self::new(addr, access),
+                _ => None,
+            })
+    }
+
+    fn select(&self, bar: u8, offset: u32, length: u32) {
+        let addr = self.addr.address;
+        let cap_offset = self.addr.offset;
+
+        let bar_offset = cap_offset + Self::BAR_OFFSET;
+        let mut data = unsafe { self.access.read(addr, bar_offset) }.to_ne_bytes();
+        data[0] = bar;
+        unsafe {
+            self.access
+                .write(addr, bar_offset, u32::from_ne_bytes(data));
+            self.access
+                .write(addr, cap_offset + Self::OFFSET_OFFSET, offset);
+            self.access
+                .write(addr, cap_offset + Self::LENGTH_OFFSET, length);
+        }
+    }
+
+    fn read_data(&self) -> [u8; 4] {
+        let data_offset = self.addr.offset + Self::DATA_OFFSET;
+        unsafe { self.access.read(self.addr.address, data_offset) }.to_ne_bytes()
+    }
+
+    fn write_data(&self, data: [u8; 4]) {
+        let data_offset = self.addr.offset + Self::DATA_OFFSET;
+        unsafe {
+            self.access
+                .write(self.addr.address, data_offset, u32::from_ne_bytes(data));
+        }
+    }
+
+    /// Reads a byte at `offset` within `bar`.
+    pub fn read_u8(&self, bar: u8, offset: u32) -> u8 {
+        self.select(bar, offset, 1);
+        let [data, ..] = self.read_data();
+        data
+    }
+
+    /// Reads a little-endian 16-bit value at `offset` within `bar`.
+    pub fn read_u16(&self, bar: u8, offset: u32) -> le16 {
+        self.select(bar, offset, 2);
+        let [b0, b1, ..] = self.read_data();
+        le16::from_le_bytes([b0, b1])
+    }
+
+    /// Reads a little-endian 32-bit value at `offset` within `bar`.
+    pub fn read_u32(&self, bar: u8, offset: u32) -> le32 {
+        self.select(bar, offset, 4);
+        le32::from_le_bytes(self.read_data())
+    }
+
+    /// Writes a byte at `offset` within `bar`.
+    pub fn write_u8(&self, bar: u8, offset: u32, value: u8) {
+        self.select(bar, offset, 1);
+        self.write_data([value, 0, 0, 0]);
+    }
+
+    /// Writes a little-endian 16-bit value at `offset` within `bar`.
+    pub fn write_u16(&self, bar: u8, offset: u32, value: le16) {
+        self.select(bar, offset, 2);
+        let [b0, b1] = value.to_le_bytes();
+        self.write_data([b0, b1, 0, 0]);
+    }
+
+    /// Writes a little-endian 32-bit value at `offset` within `bar`.
+    pub fn write_u32(&self, bar: u8, offset: u32, value: le32) {
+        self.select(bar, offset, 4);
+        self.write_data(value.to_le_bytes());
+    }
+}
+
+impl<T: ConfigRegionAccess> fmt::Debug for CfgCapAccess<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CfgCapAccess")
+            .field("addr", &self.addr)
+            .finish_non_exhaustive()
+    }
+}
+
+/// SR-IOV Extended Capability
+///
+/// This is the PCI Express Single Root I/O Virtualization extended capability of a
+/// physical function that offers [`VIRTIO_F_SR_IOV`](crate::F::SR_IOV).
+///
+/// Its registers are read once by [`find`](Self::find).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SrIovCap {
+    /// The offset of the capability in the extended configuration space.
+    pub offset: u16,
+
+    /// The number of VFs that are initially associated with the PF.
+    pub initial_vfs: u16,
+
+    /// The maximum number of VFs that can be associated with the PF.
+    pub total_vfs: u16,
+
+    /// The number of VFs that are currently enabled.
+    pub num_vfs: u16,
+
+    /// The routing ID offset of the first VF from the PF.
+    pub first_vf_offset: u16,
+
+    /// The routing ID distance between consecutive VFs.
+    pub vf_stride: u16,
+
+    /// The PCI Device ID of the VFs.
+    pub vf_device_id: u16,
+}
+
+impl SrIovCap {
+    /// The PCI Express Extended Capability ID of SR-IOV.
+    pub const ID: u16 = 0x0010;
+
+    const EXTENDED_CAPABILITIES_OFFSET: u16 = 0x100;
+    const INITIAL_VFS_OFFSET: u16 = 0x0c;
+    const NUM_VFS_OFFSET: u16 = 0x10;
+    const FIRST_VF_OFFSET_OFFSET: u16 = 0x14;
+    const VF_DEVICE_ID_OFFSET: u16 = 0x18;
+
+    /// Finds the SR-IOV extended capability of the function at `address`.
+    ///
+    /// This walks the extended capability list, which requires access to the
+    /// PCI Express extended configuration space.
+    pub fn find(address: PciAddress, access: &impl ConfigRegionAccess) -> Option<Self> {
+        let mut offset = Self::EXTENDED_CAPABILITIES_OFFSET;
+        // Each capability takes at least 4 bytes of the 4 KiB extended configuration space.
+        for _ in 0..(0x1000 - 0x100) / 4 {
+            let header = unsafe { access.read(address, offset) };
+            if header == 0 || header == u32::MAX {
+                return None;
+            }
+
+            if header as u16 == Self::ID {
+                return Some(Self::read(address, access, offset));
+            }
+
+            offset = (header >> 20) as u16 & !0b11;
+            if offset < Self::EXTENDED_CAPABILITIES_OFFSET {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    fn read(address: PciAddress, access: &impl ConfigRegionAccess, offset: u16) -> Self {
+        let read = |reg: u16| {
+            let value = unsafe { access.read(address, offset + reg) };
+            (value as u16, (value >> 16) as u16)
+        };
+
+        let (initial_vfs, total_vfs) = read(Self::INITIAL_VFS_OFFSET);
+        let (num_vfs, _) = read(Self::NUM_VFS_OFFSET);
+        let (first_vf_offset, vf_stride) = read(Self::FIRST_VF_OFFSET_OFFSET);
+        let (_, vf_device_id) = read(Self::VF_DEVICE_ID_OFFSET);
+
+        Self {
+            offset,
+            initial_vfs,
+            total_vfs,
+            num_vfs,
+            first_vf_offset,
+            vf_stride,
+            vf_device_id,
+        }
+    }
+
+    /// Returns the address of the VF with index `vf` of the PF at `pf`.
+    ///
+    /// Returns `None` if `vf` is not less than [`num_vfs`](Self::num_vfs) or
+    /// if the routing ID does not fit into the bus number range.
+    pub fn vf_address(&self, pf: PciAddress, vf: u16) -> Option<PciAddress> {
+        if vf >= self.num_vfs {
+            return None;
+        }
+
+        let pf_rid =
+            u32::from(pf.bus()) << 8 | u32::from(pf.device()) << 3 | u32::from(pf.function());
+        let rid =
+            pf_rid + u32::from(self.first_vf_offset) + u32::from(vf) * u32::from(self.vf_stride);
+        let bus = u8::try_from(rid >> 8).ok()?;
+
+        Some(PciAddress::new(
+            pf.segment(),
+            bus,
+            (rid >> 3) as u8 & 0x1f,
+            rid as u8 & 0x7,
+        ))
+    }
+
+    /// Returns an iterator over the addresses of all enabled VFs of the PF at `pf`.
+    pub fn vf_addresses(&self, pf: PciAddress) -> impl Iterator<Item = PciAddress> + '_ {
+        (0..self.num_vfs).map_while(move |vf| self.vf_address(pf, vf))
+    }
+}
+
 /// PCI Capability Data
 #[derive(Clone, Copy, Debug)]
 pub struct CapData {
@@ -267,6 +585,143 @@ impl CapData {
             notify_off_multiplier,
         })
     }
+
+    /// Returns the offset within the BAR of the queue notification address for a queue with `queue_notify_off`.
+    ///
+    /// This is `cap.offset + queue_notify_off * notify_off_multiplier`.
+    /// If `notify_off_multiplier` is 0, all queues share the same notification address.
+    ///
+    /// Returns `None` if this is not a [`VIRTIO_PCI_CAP_NOTIFY_CFG`](CapCfgType::Notify) capability.
+    pub fn notify_offset(&self, queue_notify_off: u16) -> Option<u64> {
+        let notify_off_multiplier = self.notify_off_multiplier?.to_ne();
+        let offset =
+            self.offset.to_ne() + u64::from(queue_notify_off) * u64::from(notify_off_multiplier);
+        Some(offset)
+    }
+
+    /// Returns the shared memory region described by this capability.
+    ///
+    /// For shared memory capabilities, `id` is the `shmid` of the region and
+    /// `offset` and `length` have been assembled from the [`Cap64`] layout.
+    ///
+    /// Returns `None` if this is not a [`VIRTIO_PCI_CAP_SHARED_MEMORY_CFG`](CapCfgType::SharedMemory) capability.
+    pub fn shared_memory_region(&self) -> Option<SharedMemoryRegion> {
+        if self.cfg_type != CapCfgType::SharedMemory {
+            return None;
+        }
+
+        Some(SharedMemoryRegion {
+            shmid: self.id,
+            bar: self.bar,
+            offset: self.offset.to_ne(),
+            length: self.length.to_ne(),
+        })
+    }
+
+    /// Returns the offset within the BAR of the queue notification address for the queue selected in `common_cfg`.
+    ///
+    /// See [`CapData::notify_offset`].
+    pub fn queue_notify_offset<A>(&self, common_cfg: VolatilePtr<'_, CommonCfg, A>) -> Option<u64>
+    where
+        A: RestrictAccess<ReadOnly>,
+        A::Restricted: Readable,
+    {
+        let queue_notify_off = common_cfg.queue_notify_off().read().to_ne();
+        self.notify_offset(queue_notify_off)
+    }
+}
+
+/// Shared Memory Region
+///
+/// See [`CapData::shared_memory_region`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SharedMemoryRegion {
+    /// The ID of the shared memory region.
+    pub shmid: u8,
+
+    /// The BAR containing the shared memory region.
+    pub bar: u8,
+
+    /// The offset of the shared memory region within the BAR.
+    pub offset: u64,
+
+    /// The length of the shared memory region in bytes.
+    pub length: u64,
+}
+
+/// Virtio PCI Capability Iterator
+///
+/// This iterator walks the PCI capability list of a device and yields the
+/// [`CapData`] of each well-formed virtio vendor-specific capability.
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use pci_types::{ConfigRegionAccess, EndpointHeader};
+/// use virtio::pci::{CapCfgType, CapData, CapDataIter};
+///
+/// fn find_common_cfg(header: &EndpointHeader, access: &impl ConfigRegionAccess) -> Option<CapData> {
+///     CapDataIter::new(header, access).find(|cap| cap.cfg_type == CapCfgType::Common)
+/// }
+/// ```
+pub struct CapDataIter<'a, T: ConfigRegionAccess> {
+    capabilities: CapabilityIterator<&'a T>,
+    access: &'a T,
+}
+
+impl<'a, T: ConfigRegionAccess> CapDataIter<'a, T> {
+    /// Creates a new capability iterator for the device with the given header.
+    pub fn new(header: &EndpointHeader, access: &'a T) -> Self {
+        Self {
+            capabilities: header.capabilities(access),
+            access,
+        }
+    }
+}
+
+impl<T: ConfigRegionAccess> Iterator for CapDataIter<'_, T> {
+    type Item = CapData;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.capabilities.find_map(|capability| match capability {
+            PciCapability::Vendor(addr) => CapData::read(addr, self.access),
+            _ => None,
+        })
+    }
+}
+
+impl<'a, T: ConfigRegionAccess> CapDataIter<'a, T> {
+    /// Returns an iterator over the shared memory regions of the device.
+    ///
+    /// This yields a [`SharedMemoryRegion`] for each
+    /// [`VIRTIO_PCI_CAP_SHARED_MEMORY_CFG`](CapCfgType::SharedMemory) capability.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use virtio_spec as virtio;
+    /// use pci_types::{ConfigRegionAccess, EndpointHeader};
+    /// use virtio::pci::{CapDataIter, SharedMemoryRegion};
+    ///
+    /// fn find_dax_window(
+    ///     header: &EndpointHeader,
+    ///     access: &impl ConfigRegionAccess,
+    /// ) -> Option<SharedMemoryRegion> {
+    ///     CapDataIter::new(header, access)
+    ///         .shared_memory_regions()
+    ///         .find(|region| region.shmid == virtio::fs::SHMCAP_ID_CACHE)
+    /// }
+    /// ```
+    pub fn shared_memory_regions(self) -> impl Iterator<Item = SharedMemoryRegion> + 'a {
+        self.filter_map(|cap| cap.shared_memory_region())
+    }
+}
+
+impl<T: ConfigRegionAccess> fmt::Debug for CapDataIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapDataIter").finish_non_exhaustive()
+    }
 }
 
 /// PCI Capability Configuration Type
@@ -358,6 +813,11 @@ pub struct CommonCfg {
 
     /// Configuration atomicity value.  The device changes this every time the
     /// configuration noticeably changes.
+    ///
+    /// The driver reads this before and after accessing the device-specific configuration.
+    /// If the values differ, the configuration accesses were not atomic and the driver has to
+    /// access the configuration again.
+    /// [`DeviceConfigSpace::read_config_with`] implements this loop.
     #[access(ReadOnly)]
     config_generation: u8,
 
@@ -451,6 +911,296 @@ impl_wide_field_access! {
     }
 }
 
+/// Selected Virtqueue
+///
+/// This provides access to the per-queue fields of [`CommonCfg`] for a single virtqueue.
+/// Every accessor writes the queue index to `queue_select` before returning the field,
+/// so views of different virtqueues can be used interleaved.
+/// A returned field refers to this virtqueue until `queue_select` is written again.
+///
+/// # Examples
+///
+/// ```rust
+/// # use virtio_spec as virtio;
+/// use virtio::le16;
+/// use virtio::pci::{CommonCfg, SelectedQueue};
+/// use volatile::VolatilePtr;
+///
+/// fn enable_queue(common_cfg: VolatilePtr<'_, CommonCfg>, queue: u16, desc: u64, driver: u64, device: u64) -> u16 {
+///     let queue = SelectedQueue::new(common_cfg, queue);
+///     let size = queue.size().read().to_ne();
+///     queue.desc().write(desc.into());
+///     queue.driver().write(driver.into());
+///     queue.device().write(device.into());
+///     queue.enable().write(le16::from_ne(1));
+///     size
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SelectedQueue<'a> {
+    common_cfg: VolatilePtr<'a, CommonCfg>,
+    index: u16,
+}
+
+impl<'a> SelectedQueue<'a> {
+    /// Creates a view of the virtqueue with index `queue`.
+    ///
+    /// This does not write to `queue_select` until a field is accessed.
+    pub fn new(common_cfg: VolatilePtr<'a, CommonCfg>, queue: u16) -> Self {
+        Self {
+            common_cfg,
+            index: queue,
+        }
+    }
+
+    fn select(&self) -> VolatilePtr<'a, CommonCfg> {
+        self.common_cfg
+            .queue_select()
+            .write(le16::from_ne(self.index));
+        self.common_cfg
+    }
+
+    /// Returns the index of this virtqueue.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Queue Size (`queue_size`)
+    #[doc(alias = "queue_size")]
+    pub fn size(&self) -> VolatilePtr<'a, le16> {
+        self.select().queue_size()
+    }
+
+    /// Queue Vector for MSI-X (`queue_msix_vector`)
+    ///
+    /// See also [`CommonCfgVolatileMsixVectorAccess`].
+    #[doc(alias = "queue_msix_vector")]
+    pub fn msix_vector(&self) -> VolatilePtr<'a, le16> {
+        self.select().queue_msix_vector()
+    }
+
+    /// Queue Enable (`queue_enable`)
+    #[doc(alias = "queue_enable")]
+    pub fn enable(&self) -> VolatilePtr<'a, le16> {
+        self.select().queue_enable()
+    }
+
+    /// Queue Notify Offset (`queue_notify_off`)
+    ///
+    /// See [`CapData::notify_offset`].
+    #[doc(alias = "queue_notify_off")]
+    pub fn notify_off(&self) -> VolatilePtr<'a, le16, ReadOnly> {
+        self.select().queue_notify_off()
+    }
+
+    /// Descriptor Area Address (`queue_desc`)
+    #[doc(alias = "queue_desc")]
+    pub fn desc(&self) -> WideVolatilePtr<'a, le32, ReadWrite> {
+        self.select().queue_desc()
+    }
+
+    /// Driver Area Address (`queue_driver`)
+    #[doc(alias = "queue_driver")]
+    pub fn driver(&self) -> WideVolatilePtr<'a, le32, ReadWrite> {
+        self.select().queue_driver()
+    }
+
+    /// Device Area Address (`queue_device`)
+    #[doc(alias = "queue_device")]
+    pub fn device(&self) -> WideVolatilePtr<'a, le32, ReadWrite> {
+        self.select().queue_device()
+    }
+}
+
+impl Transport for VolatilePtr<'_, CommonCfg> {
+    fn read_device_status(self) -> DeviceStatus {
+        self.device_status().read()
+    }
+
+    fn write_device_status(self, status: DeviceStatus) {
+        self.device_status().write(status);
+    }
+
+    fn read_device_features(self, select: u32) -> u32 {
+        self.device_feature_select().write(le32::from_ne(select));
+        self.device_feature().read().to_ne()
+    }
+
+    fn write_driver_features(self, select: u32, window: u32) {
+        self.driver_feature_select().write(le32::from_ne(select));
+        self.driver_feature().write(le32::from_ne(window));
+    }
+
+    fn read_config_generation(self) -> u32 {
+        self.config_generation().read().into()
+    }
+
+    fn select_queue(self, queue: u16) {
+        self.queue_select().write(le16::from_ne(queue));
+    }
+
+    fn read_queue_size_max(self) -> u16 {
+        self.queue_size().read().to_ne()
+    }
+
+    fn write_queue_size(self, size: u16) {
+        self.queue_size().write(le16::from_ne(size));
+    }
+
+    fn enable_queue(self) {
+        self.queue_enable().write(le16::from_ne(1));
+    }
+
+    fn read_queue_notify_off(self) -> Option<u16> {
+        Some(self.queue_notify_off().read().to_ne())
+    }
+}
+
+impl<A> QueueAddresses for VolatilePtr<'_, CommonCfg, A>
+where
+    A: RestrictAccess<ReadWrite>,
+    A::Restricted: Writable,
+{
+    fn set_queue_desc(self, addr: u64) {
+        self.queue_desc().write(le64::from_ne(addr));
+    }
+
+    fn set_queue_driver(self, addr: u64) {
+        self.queue_driver().write(le64::from_ne(addr));
+    }
+
+    fn set_queue_device(self, addr: u64) {
+        self.queue_device().write(le64::from_ne(addr));
+    }
+}
+
+/// Vector value used to disable MSI-X for configuration changes or a virtqueue.
+#[doc(alias = "VIRTIO_MSI_NO_VECTOR")]
+pub const MSI_NO_VECTOR: u16 = 0xffff;
+
+/// MSI-X vector access for [`CommonCfg`]
+///
+/// These methods map [`MSI_NO_VECTOR`] to `None`.
+///
+/// After writing a vector, the driver has to read it back to check whether the
+/// device was able to allocate resources for it. If not, reading it back returns `None`.
+pub trait CommonCfgVolatileMsixVectorAccess<'a, A> {
+    /// Reads the configuration vector for MSI-X.
+    fn read_config_msix_vector(self) -> Option<u16>
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Readable;
+
+    /// Writes the configuration vector for MSI-X.
+    fn write_config_msix_vector(self, vector: Option<u16>)
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Writable;
+
+    /// Reads the queue vector for MSI-X of the selected virtqueue.
+    fn read_queue_msix_vector(self) -> Option<u16>
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Readable;
+
+    /// Writes the queue vector for MSI-X of the selected virtqueue.
+    fn write_queue_msix_vector(self, vector: Option<u16>)
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Writable;
+}
+
+fn from_msix_vector(vector: le16) -> Option<u16> {
+    let vector = vector.to_ne();
+    (vector != MSI_NO_VECTOR).then_some(vector)
+}
+
+fn into_msix_vector(vector: Option<u16>) -> le16 {
+    le16::from_ne(vector.unwrap_or(MSI_NO_VECTOR))
+}
+
+impl<'a, A> CommonCfgVolatileMsixVectorAccess<'a, A> for VolatilePtr<'a, CommonCfg, A> {
+    fn read_config_msix_vector(self) -> Option<u16>
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Readable,
+    {
+        from_msix_vector(self.config_msix_vector().read())
+    }
+
+    fn write_config_msix_vector(self, vector: Option<u16>)
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Writable,
+    {
+        self.config_msix_vector().write(into_msix_vector(vector));
+    }
+
+    fn read_queue_msix_vector(self) -> Option<u16>
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Readable,
+    {
+        from_msix_vector(self.queue_msix_vector().read())
+    }
+
+    fn write_queue_msix_vector(self, vector: Option<u16>)
+    where
+        A: RestrictAccess<ReadWrite>,
+        A::Restricted: Writable,
+    {
+        self.queue_msix_vector().write(into_msix_vector(vector));
+    }
+}
+
+/// Returns the virtqueue identifier for available buffer notifications of the queue selected in `common_cfg`.
+///
+/// If [`VIRTIO_F_NOTIF_CONFIG_DATA`] has been negotiated, this is `queue_notify_data`.
+/// Otherwise, this is the queue index (`queue_select`).
+///
+/// Without [`VIRTIO_F_NOTIFICATION_DATA`], the driver writes this value to the queue notification address.
+/// With `VIRTIO_F_NOTIFICATION_DATA`, the driver uses it as [`NotificationData::vqn`].
+///
+/// [`VIRTIO_F_NOTIF_CONFIG_DATA`]: crate::F::NOTIF_CONFIG_DATA
+/// [`VIRTIO_F_NOTIFICATION_DATA`]: crate::F::NOTIFICATION_DATA
+pub fn queue_notify_id(common_cfg: VolatilePtr<'_, CommonCfg>, features: crate::F) -> u16 {
+    if features.contains(crate::F::NOTIF_CONFIG_DATA) {
+        common_cfg.queue_notify_data().read().to_ne()
+    } else {
+        common_cfg.queue_select().read().to_ne()
+    }
+}
+
+/// Resets the virtqueue with index `queue`.
+///
+/// This selects the queue, writes 1 to `queue_reset`, and polls `queue_reset` until the device presents 0.
+/// `relax` is called between polls and can yield, sleep, or stop waiting by returning `false`.
+///
+/// Returns whether the reset has completed.
+///
+/// Afterwards, the queue is disabled.
+/// This function does not re-enable the queue.
+/// To use the queue again, the caller has to set up the queue and write 1 to `queue_enable`.
+///
+/// This requires [`VIRTIO_F_RING_RESET`] to have been negotiated.
+/// See _Basic Facilities of a Virtio Device / Virtqueues / Virtqueue Reset_.
+///
+/// [`VIRTIO_F_RING_RESET`]: crate::F::RING_RESET
+pub fn reset_queue(
+    common_cfg: VolatilePtr<'_, CommonCfg>,
+    queue: u16,
+    mut relax: impl FnMut() -> bool,
+) -> bool {
+    common_cfg.queue_select().write(le16::from_ne(queue));
+    common_cfg.queue_reset().write(le16::from_ne(1));
+    while common_cfg.queue_reset().read().to_ne() != 0 {
+        if !relax() {
+            return false;
+        }
+    }
+    true
+}
+
 impl<'a, A> DeviceConfigSpace for VolatilePtr<'a, CommonCfg, A>
 where
     A: RestrictAccess<ReadOnly>,
@@ -474,11 +1224,215 @@ where
 
 virtio_bitflags! {
     /// ISR Status
+    ///
+    /// The ISR status is found at the bar and offset within the [`VIRTIO_PCI_CAP_ISR_CFG`] capability.
+    /// See [`IsrStatus::read`].
+    ///
+    /// [`VIRTIO_PCI_CAP_ISR_CFG`]: CapCfgType::Isr
     pub struct IsrStatus: u8 {
         /// Queue Interrupt
+        #[doc(alias = "VIRTIO_PCI_ISR_QUEUE")]
         const QUEUE_INTERRUPT = 1 << 0;
 
         /// Device Configuration Interrupt
+        #[doc(alias = "VIRTIO_PCI_ISR_CONFIG")]
         const DEVICE_CONFIGURATION_INTERRUPT = 1 << 1;
     }
 }
+
+impl IsrStatus {
+    /// Reads the ISR status register.
+    ///
+    /// <div class="warning">
+    ///
+    /// Reading the ISR status register resets it to 0 and de-asserts the
+    /// legacy interrupt, so it must only be read once per interrupt.
+    ///
+    /// </div>
+    pub fn read<A: Readable>(isr: VolatilePtr<'_, u8, A>) -> Self {
+        Self::from_bits_retain(isr.read())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr::NonNull;
+
+    use super::*;
+    use crate::{net, NegotiationError};
+
+    fn negotiate(device_features: u32, supported: net::F) -> Result<net::F, NegotiationError> {
+        let mut common_cfg: CommonCfg = unsafe { mem::zeroed() };
+        // Every window reads the same value, so bit 0 is VIRTIO_F_VERSION_1 in window 1.
+        common_cfg.device_feature = le32::from_ne(device_features);
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut common_cfg)) };
+        ptr.negotiate_features(supported)
+    }
+
+    #[test]
+    fn negotiate_requirements() {
+        let version_1 = net::F::from(crate::F::VERSION_1);
+        let device_features = (net::F::GUEST_CSUM | net::F::GUEST_TSO4 | net::F::GUEST_ECN)
+            .bits()
+            .to_ne() as u32
+            | 1;
+
+        let features = negotiate(device_features, net::F::GUEST_TSO4 | net::F::GUEST_ECN);
+        assert_eq!(features, Ok(version_1));
+
+        let supported = net::F::GUEST_CSUM | net::F::GUEST_TSO4 | net::F::GUEST_ECN;
+        let features = negotiate(device_features, supported);
+        assert_eq!(features, Ok(supported | version_1));
+
+        assert_eq!(
+            negotiate(0, supported),
+            Err(NegotiationError::Version1NotOffered)
+        );
+    }
+
+    #[test]
+    fn selected_queue() {
+        let mut common_cfg: CommonCfg = unsafe { mem::zeroed() };
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut common_cfg)) };
+        let queue_select = || ptr.queue_select().read().to_ne();
+
+        let queue0 = SelectedQueue::new(ptr, 0);
+        let queue1 = SelectedQueue::new(ptr, 1);
+
+        queue1.size().write(le16::from_ne(64));
+        assert_eq!(queue_select(), 1);
+        queue0.size().write(le16::from_ne(128));
+        assert_eq!(queue_select(), 0);
+        queue1.enable().write(le16::from_ne(1));
+        assert_eq!(queue_select(), 1);
+        queue0.desc().write(0x1000.into());
+        assert_eq!(queue_select(), 0);
+        assert_eq!(queue1.notify_off().read().to_ne(), 0);
+        assert_eq!(queue_select(), 1);
+    }
+
+    struct ExtendedConfigSpace([u32; 0x400]);
+
+    impl ConfigRegionAccess for ExtendedConfigSpace {
+        unsafe fn read(&self, _address: PciAddress, offset: u16) -> u32 {
+            self.0[usize::from(offset / 4)]
+        }
+
+        unsafe fn write(&self, _address: PciAddress, _offset: u16, _value: u32) {
+            unreachable!("SR-IOV parsing must not write config space")
+        }
+    }
+
+    #[test]
+    fn sr_iov_cap() {
+        let mut space = ExtendedConfigSpace([0; 0x400]);
+        // AER capability at 0x100, pointing to SR-IOV at 0x140
+        space.0[0x100 / 4] = 0x140 << 20 | 0x1 << 16 | 0x0001;
+        space.0[0x140 / 4] = 0x1 << 16 | u32::from(SrIovCap::ID);
+        space.0[0x14c / 4] = 8 << 16 | 8;
+        space.0[0x150 / 4] = 2;
+        space.0[0x154 / 4] = 2 << 16 | 0x80;
+        space.0[0x158 / 4] = 0x1041 << 16;
+
+        let pf = PciAddress::new(0, 1, 0, 0);
+        let cap = SrIovCap::find(pf, &space).unwrap();
+        assert_eq!(
+            cap,
+            SrIovCap {
+                offset: 0x140,
+                initial_vfs: 8,
+                total_vfs: 8,
+                num_vfs: 2,
+                first_vf_offset: 0x80,
+                vf_stride: 2,
+                vf_device_id: 0x1041,
+            }
+        );
+
+        let mut vfs = cap.vf_addresses(pf);
+        assert_eq!(vfs.next(), Some(PciAddress::new(0, 1, 0x10, 0)));
+        assert_eq!(vfs.next(), Some(PciAddress::new(0, 1, 0x10, 2)));
+        assert_eq!(vfs.next(), None);
+
+        space.0[0x100 / 4] = 0x0001;
+        assert_eq!(SrIovCap::find(pf, &space), None);
+    }
+
+    #[test]
+    fn notify_offset() {
+        let mut cap = CapData {
+            cfg_type: CapCfgType::Notify,
+            bar: 4,
+            id: 0,
+            offset: le64::from_ne(0x3000),
+            length: le64::from_ne(0x1000),
+            notify_off_multiplier: Some(le32::from_ne(4)),
+        };
+        assert_eq!(cap.notify_offset(0), Some(0x3000));
+        assert_eq!(cap.notify_offset(3), Some(0x300c));
+
+        cap.notify_off_multiplier = Some(le32::from_ne(0));
+        assert_eq!(cap.notify_offset(3), Some(0x3000));
+
+        cap.cfg_type = CapCfgType::Common;
+        cap.notify_off_multiplier = None;
+        assert_eq!(cap.notify_offset(3), None);
+    }
+
+    #[test]
+    fn shared_memory_region() {
+        let mut cap = CapData {
+            cfg_type: CapCfgType::SharedMemory,
+            bar: 2,
+            id: 1,
+            offset: le64::from_ne(0x1_0000_0000),
+            length: le64::from_ne(0x2_0000_0000),
+            notify_off_multiplier: None,
+        };
+        assert_eq!(
+            cap.shared_memory_region(),
+            Some(SharedMemoryRegion {
+                shmid: 1,
+                bar: 2,
+                offset: 0x1_0000_0000,
+                length: 0x2_0000_0000,
+            })
+        );
+
+        cap.cfg_type = CapCfgType::Device;
+        assert_eq!(cap.shared_memory_region(), None);
+    }
+
+    #[test]
+    fn queue_addresses() {
+        let mut common_cfg: CommonCfg = unsafe { mem::zeroed() };
+        let ptr = unsafe { VolatilePtr::new(NonNull::from(&mut common_cfg)) };
+
+        ptr.set_queue_desc(0x1122_3344_5566_7788);
+        assert_eq!(
+            common_cfg.queue_desc_low.to_le_bytes(),
+            [0x88, 0x77, 0x66, 0x55]
+        );
+        assert_eq!(
+            common_cfg.queue_desc_high.to_le_bytes(),
+            [0x44, 0x33, 0x22, 0x11]
+        );
+    }
+
+    #[test]
+    fn queue_notify_id() {
+        let mut common_cfg: CommonCfg = unsafe { mem::zeroed() };
+        common_cfg.queue_select = le16::from_ne(3);
+        common_cfg.queue_notify_data = le16::from_ne(0x1234);
+        let common_cfg = unsafe { VolatilePtr::new(NonNull::from(&mut common_cfg)) };
+
+        assert_eq!(super::queue_notify_id(common_cfg, crate::F::VERSION_1), 3);
+        assert_eq!(
+            super::queue_notify_id(
+                common_cfg,
+                crate::F::VERSION_1 | crate::F::NOTIF_CONFIG_DATA
+            ),
+            0x1234
+        );
+    }
+}
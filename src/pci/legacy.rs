@@ -0,0 +1,113 @@
+//! Legacy Interfaces for Virtio over PCI bus.
+//!
+//! Transitional devices expose the legacy configuration layout in the I/O space of BAR0.
+//!
+//! <div class="warning">
+//!
+//! The legacy interface uses the native endian of the guest instead of little-endian.
+//! Feature negotiation is limited to the lower 32 feature bits.
+//!
+//! </div>
+
+use volatile::access::{ReadOnly, ReadWrite};
+use volatile_macro::VolatileFieldAccess;
+
+use super::IsrStatus;
+use crate::DeviceStatus;
+
+/// The alignment of legacy virtqueues.
+#[doc(alias = "VIRTIO_PCI_VRING_ALIGN")]
+pub const QUEUE_ALIGN: usize = 4096;
+
+/// The shift of the physical page number in [`queue_address`](CommonCfgVolatileFieldAccess::queue_address).
+#[doc(alias = "VIRTIO_PCI_QUEUE_ADDR_SHIFT")]
+pub const QUEUE_ADDR_SHIFT: u32 = 12;
+
+/// The offset of the device-specific configuration if MSI-X is disabled.
+pub const DEVICE_CFG_OFFSET: usize = 20;
+
+/// The offset of the device-specific configuration if MSI-X is enabled.
+pub const DEVICE_CFG_OFFSET_MSIX: usize = 24;
+
+/// Legacy Common Configuration Structure
+///
+/// This structure is found at the start of BAR0.
+/// If MSI-X is enabled for the device, [`MsixCfg`] follows this structure.
+///
+/// Use [`CommonCfgVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_pci_legacy")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct CommonCfg {
+    /// The lower 32 bits of the features offered by the device.
+    #[access(ReadOnly)]
+    device_features: u32,
+
+    /// The lower 32 bits of the features accepted by the driver.
+    #[access(ReadWrite)]
+    driver_features: u32,
+
+    /// The physical page number of the selected virtqueue.
+    ///
+    /// This is the guest-physical address shifted by [`QUEUE_ADDR_SHIFT`].
+    /// Writing 0 disables the virtqueue.
+    #[access(ReadWrite)]
+    queue_address: u32,
+
+    /// The size of the selected virtqueue.
+    /// A 0 means the queue is unavailable.
+    #[access(ReadOnly)]
+    queue_size: u16,
+
+    /// Queue Select. The driver selects which virtqueue the following
+    /// fields refer to.
+    #[access(ReadWrite)]
+    queue_select: u16,
+
+    /// The driver writes the index of a virtqueue here to notify the device.
+    #[access(ReadWrite)]
+    queue_notify: u16,
+
+    /// The driver writes the device status here. Writing 0 into this field resets the device.
+    #[access(ReadWrite)]
+    device_status: DeviceStatus,
+
+    /// ISR Status.
+    ///
+    /// Reading this field resets it to 0. See [`IsrStatus::read`].
+    #[access(ReadOnly)]
+    isr_status: IsrStatus,
+}
+
+/// Legacy MSI-X Configuration Structure
+///
+/// This structure follows [`CommonCfg`] if MSI-X is enabled for the device.
+///
+/// Use [`MsixCfgVolatileFieldAccess`] to work with this struct.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct MsixCfg {
+    /// The configuration vector for MSI-X.
+    #[access(ReadWrite)]
+    config_msix_vector: u16,
+
+    /// The queue vector for MSI-X of the selected virtqueue.
+    #[access(ReadWrite)]
+    queue_msix_vector: u16,
+}
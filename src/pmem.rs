@@ -0,0 +1,103 @@
+//! PMEM Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::pmem::F;
+use crate::{le32, le64};
+
+/// PMEM Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_pmem_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The start address of the guest physical address range to be hotplugged into the guest address space using the pmem API.
+    #[access(ReadOnly)]
+    start: le64,
+
+    /// The length of this address range.
+    #[access(ReadOnly)]
+    size: le64,
+}
+
+/// Shared Memory Region ID
+///
+/// If [`VIRTIO_PMEM_F_SHMEM_REGION`](F::SHMEM_REGION) is negotiated, the device exposes the address range in the shared memory region with this ID.
+#[doc(alias = "VIRTIO_PMEM_SHMEM_REGION_ID")]
+pub const SHMEM_REGION_ID: u8 = 0;
+
+/// Request Type
+#[doc(alias = "VIRTIO_PMEM_REQ_TYPE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum ReqType {
+    #[doc(alias = "VIRTIO_PMEM_REQ_TYPE_FLUSH")]
+    Flush = 0,
+}
+
+/// Flush Request
+#[doc(alias = "virtio_pmem_req")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Req {
+    /// See [`ReqType`].
+    pub type_: le32,
+}
+
+le_bytes_impl! {
+    Req {
+        type_: le32,
+    }
+}
+
+/// Response Type
+#[doc(alias = "VIRTIO_PMEM_RESP_TYPE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum RespType {
+    #[doc(alias = "VIRTIO_PMEM_RESP_TYPE_OK")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_PMEM_RESP_TYPE_EIO")]
+    Eio = 1,
+}
+
+/// Flush Response
+#[doc(alias = "virtio_pmem_resp")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Resp {
+    /// See [`RespType`].
+    pub ret: le32,
+}
@@ -0,0 +1,149 @@
+//! Pstore Device
+//!
+//! <div class="warning">
+//!
+//! This device is not part of a released VIRTIO specification yet.
+//! These definitions follow the virtio-pstore proposal and may change.
+//!
+//! </div>
+//!
+//! The pstore device persists guest logs such as kernel crash dumps on the host.
+//! Each request on its virtqueue consists of a device-readable [`Req`] and a device-writable [`Res`].
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::pstore::F;
+use crate::{le16, le32, le64};
+
+endian_bitflags! {
+    /// Configuration Flags
+    #[doc(alias = "VIRTIO_PSTORE_CONFIG_FL")]
+    pub struct ConfigFl: le32 {
+        /// The device accepts [`VIRTIO_PSTORE_TYPE_DMESG`](Type::Dmesg) records.
+        #[doc(alias = "VIRTIO_PSTORE_CONFIG_FL_DMESG")]
+        const DMESG = 1 << 0;
+    }
+}
+
+/// Pstore Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_pstore_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The size of the buffer the driver should use for records in bytes.
+    #[access(ReadOnly)]
+    bufsize: le32,
+
+    #[access(ReadOnly)]
+    flags: ConfigFl,
+}
+
+/// Command
+#[doc(alias = "VIRTIO_PSTORE_CMD")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Cmd {
+    #[doc(alias = "VIRTIO_PSTORE_CMD_NULL")]
+    Null = 0,
+
+    #[doc(alias = "VIRTIO_PSTORE_CMD_OPEN")]
+    Open = 1,
+
+    #[doc(alias = "VIRTIO_PSTORE_CMD_READ")]
+    Read = 2,
+
+    #[doc(alias = "VIRTIO_PSTORE_CMD_WRITE")]
+    Write = 3,
+
+    #[doc(alias = "VIRTIO_PSTORE_CMD_ERASE")]
+    Erase = 4,
+
+    #[doc(alias = "VIRTIO_PSTORE_CMD_CLOSE")]
+    Close = 5,
+}
+
+/// Record Type
+#[doc(alias = "VIRTIO_PSTORE_TYPE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Type {
+    #[doc(alias = "VIRTIO_PSTORE_TYPE_UNKNOWN")]
+    Unknown = 0,
+
+    /// Kernel log
+    #[doc(alias = "VIRTIO_PSTORE_TYPE_DMESG")]
+    Dmesg = 1,
+}
+
+/// Request Header
+#[doc(alias = "virtio_pstore_req")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Req {
+    /// See [`Cmd`].
+    pub cmd: le16,
+    /// See [`Type`].
+    pub type_: le16,
+    pub flags: le32,
+    /// The record ID.
+    pub id: le64,
+    /// The record count.
+    pub count: le32,
+    pub reserved: le32,
+}
+
+le_bytes_impl! {
+    Req {
+        cmd: le16,
+        type_: le16,
+        flags: le32,
+        id: le64,
+        count: le32,
+        reserved: le32,
+    }
+}
+
+/// Response Header
+#[doc(alias = "virtio_pstore_res")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Res {
+    /// See [`Cmd`].
+    pub cmd: le16,
+    /// See [`Type`].
+    pub type_: le16,
+    /// The return value of the command (0 on success, a negative error number otherwise).
+    pub ret: le32,
+}
@@ -1,28 +1,385 @@
 //! Packed virtqueue definitions
 
+#[cfg(feature = "alloc")]
+mod alloc;
+
+use core::alloc::Layout;
+use core::iter::FusedIterator;
+
 use bitfield_struct::bitfield;
+use volatile_macro::VolatileFieldAccess;
 
+use crate::virtq::DescChainError;
 use crate::{le16, le32, le64, virtq, RingEventFlags};
 
+/// The maximum packed virtqueue size.
+pub const MAX_SIZE: u16 = 1 << 15;
+
+/// Returns whether `size` is a valid packed virtqueue size.
+///
+/// Packed virtqueue sizes are nonzero and at most 2<sup>15</sup>,
+/// but do not have to be powers of 2.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::pvirtq;
+///
+/// const _: () = assert!(pvirtq::is_valid_size(96));
+/// assert!(!pvirtq::is_valid_size(0));
+/// assert!(!pvirtq::is_valid_size(0x8001));
+/// ```
+pub const fn is_valid_size(size: u16) -> bool {
+    size != 0 && size <= MAX_SIZE
+}
+
 /// Packed Virtqueue Descriptor
 #[doc(alias = "pvirtq_desc")]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Desc {
     /// Buffer Address.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub addr: le64,
 
     /// Buffer Length.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub len: le32,
 
     /// Buffer ID.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub id: le16,
 
     /// The flags depending on descriptor type.
     pub flags: virtq::DescF,
 }
 
+defmt_format_impl! {
+    Desc {
+        addr,
+        len,
+        id,
+        flags,
+    }
+}
+
+impl Desc {
+    /// Creates a new descriptor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::pvirtq::Desc;
+    /// use virtio::virtq::DescF;
+    ///
+    /// const DESC: Desc = Desc::new(0x1000, 0x10, 7, DescF::AVAIL);
+    ///
+    /// assert_eq!(DESC.id.to_ne(), 7);
+    /// ```
+    pub const fn new(addr: u64, len: u32, id: u16, flags: virtq::DescF) -> Self {
+        Self {
+            addr: le64::from_ne(addr),
+            len: le32::from_ne(len),
+            id: le16::from_ne(id),
+            flags,
+        }
+    }
+}
+
+/// Packed Virtqueue Descriptor Ring
+///
+/// The descriptor ring has to be aligned to 16 bytes.
+#[doc(alias = "pvirtq_desc_ring")]
+#[derive(Debug)]
+#[repr(C, align(16))]
+pub struct DescRing(pub [Desc]);
+
+virtq::desc_table_impl!(DescRing, Desc);
+
+/// Descriptor Chain Iterator
+///
+/// This iterator starts at a position in the descriptor ring and yields
+/// the descriptors of the chain at consecutive positions, wrapping around at
+/// the end of the ring, until it reaches a descriptor without
+/// [`VIRTQ_DESC_F_NEXT`](virtq::DescF::NEXT).
+///
+/// Since a chain can never be longer than the descriptor ring, this iterator
+/// yields at most `ring.len()` descriptors.
+/// If the chain is malformed, an error is yielded once and the iterator is fused.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::pvirtq::{Desc, DescChain};
+/// use virtio::virtq::DescF;
+/// use virtio::{le16, le32, le64};
+///
+/// let desc = |flags, id| Desc {
+///     addr: le64::from_ne(0),
+///     len: le32::from_ne(0),
+///     id: le16::from_ne(id),
+///     flags,
+/// };
+/// let ring = [desc(DescF::empty(), 7), desc(DescF::NEXT, 0)];
+///
+/// let mut chain = DescChain::new(&ring, 1);
+/// assert_eq!(chain.by_ref().count(), 2);
+/// assert_eq!(chain.id(), Some(7));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DescChain<'a> {
+    ring: &'a [Desc],
+    pos: Option<u16>,
+    remaining: usize,
+    id: Option<u16>,
+}
+
+impl<'a> DescChain<'a> {
+    /// Creates a new descriptor chain iterator starting at `pos`.
+    pub fn new(ring: &'a [Desc], pos: u16) -> Self {
+        Self {
+            ring,
+            pos: Some(pos),
+            remaining: ring.len(),
+            id: None,
+        }
+    }
+
+    /// Returns the buffer ID of the chain.
+    ///
+    /// The buffer ID is only available from the last descriptor of the chain.
+    /// This returns `None` until that descriptor has been yielded.
+    pub fn id(&self) -> Option<u16> {
+        self.id
+    }
+}
+
+impl<'a> Iterator for DescChain<'a> {
+    type Item = Result<&'a Desc, DescChainError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos.take()?;
+
+        let Some(desc) = self.ring.get(usize::from(pos)) else {
+            return Some(Err(DescChainError::OutOfBounds { index: pos }));
+        };
+
+        if self.remaining == 0 {
+            return Some(Err(DescChainError::Loop));
+        }
+        self.remaining -= 1;
+
+        if desc.flags.contains(virtq::DescF::NEXT) {
+            let next = usize::from(pos) + 1;
+            let next = if next == self.ring.len() { 0 } else { next };
+            self.pos = Some(next as u16);
+        } else {
+            self.id = Some(desc.id.to_ne());
+        }
+
+        Some(Ok(desc))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.pos {
+            Some(_) => (1, Some(self.remaining + 1)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl FusedIterator for DescChain<'_> {}
+
+/// Ring Position
+///
+/// This tracks a position in the descriptor ring together with the
+/// corresponding wrap counter, which is flipped each time the position wraps
+/// around at the end of the ring.
+///
+/// The driver keeps one of these for making descriptors available
+/// (Driver Ring Wrap Counter) and one for reading used descriptors.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::pvirtq::RingPos;
+/// use virtio::virtq::DescF;
+///
+/// let mut pos = RingPos::new(2);
+/// assert_eq!(pos.make_avail_flags(), DescF::AVAIL);
+///
+/// pos.advance(2);
+/// assert_eq!(pos.pos(), 0);
+/// assert_eq!(pos.make_avail_flags(), DescF::USED);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RingPos {
+    pos: u16,
+    wrap_counter: bool,
+    size: u16,
+}
+
+impl RingPos {
+    /// Creates a new ring position at the start of a ring with `size` descriptors.
+    ///
+    /// The wrap counter starts at 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub const fn new(size: u16) -> Self {
+        assert!(size > 0);
+        Self {
+            pos: 0,
+            wrap_counter: true,
+            size,
+        }
+    }
+
+    /// Returns the position in the descriptor ring.
+    pub const fn pos(&self) -> u16 {
+        self.pos
+    }
+
+    /// Returns the wrap counter.
+    pub const fn wrap_counter(&self) -> bool {
+        self.wrap_counter
+    }
+
+    /// Advances the position by `n` descriptors, flipping the wrap counter when wrapping around.
+    pub fn advance(&mut self, n: u16) {
+        let mut pos = u32::from(self.pos) + u32::from(n);
+        let size = u32::from(self.size);
+        while pos >= size {
+            pos -= size;
+            self.wrap_counter = !self.wrap_counter;
+        }
+        self.pos = pos as u16;
+    }
+
+    /// Returns the [`AVAIL`](virtq::DescF::AVAIL) and [`USED`](virtq::DescF::USED)
+    /// flags for making a descriptor at this position available.
+    ///
+    /// `AVAIL` is set to the wrap counter and `USED` to its inverse.
+    pub fn make_avail_flags(&self) -> virtq::DescF {
+        if self.wrap_counter {
+            virtq::DescF::AVAIL
+        } else {
+            virtq::DescF::USED
+        }
+    }
+
+    /// Returns whether a descriptor at this position with `flags` has been used by the device.
+    ///
+    /// This is the case if both [`AVAIL`](virtq::DescF::AVAIL) and
+    /// [`USED`](virtq::DescF::USED) match the wrap counter.
+    pub fn is_used(&self, flags: virtq::DescF) -> bool {
+        let avail = flags.contains(virtq::DescF::AVAIL);
+        let used = flags.contains(virtq::DescF::USED);
+        avail == used && used == self.wrap_counter
+    }
+}
+
+/// Returns whether the other side needs to be notified.
+///
+/// With [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) and
+/// [`RING_EVENT_FLAGS_DESC`](RingEventFlags::Desc), the driver (device) has
+/// to notify the device (driver) if the ring position moved past the descriptor
+/// at `desc_event_off` with the wrap counter `desc_event_wrap` (see [`EventSuppress::desc_event`])
+/// while making `num_added` descriptors available (used) up to `next`.
+///
+/// See also [`virtq::needs_notify`].
+pub fn needs_notify(
+    desc_event_off: u16,
+    desc_event_wrap: bool,
+    next: RingPos,
+    num_added: u16,
+) -> bool {
+    let new_idx = next.pos;
+    let old_idx = new_idx.wrapping_sub(num_added);
+    let event_idx = if desc_event_wrap == next.wrap_counter {
+        desc_event_off
+    } else {
+        desc_event_off.wrapping_sub(next.size)
+    };
+    virtq::needs_notify(event_idx, new_idx, old_idx)
+}
+
+/// The memory layout of a packed virtqueue in a single contiguous region.
+///
+/// See [`layout`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QueueLayout {
+    /// The layout of the whole region.
+    pub layout: Layout,
+
+    /// The byte offset of the [`DescRing`].
+    pub desc_offset: usize,
+
+    /// The byte offset of the driver [`EventSuppress`] structure.
+    pub driver_offset: usize,
+
+    /// The byte offset of the device [`EventSuppress`] structure.
+    pub device_offset: usize,
+}
+
+/// Computes the layout of a packed virtqueue in a single contiguous region.
+///
+/// The descriptor ring, the driver event suppression structure, and the
+/// device event suppression structure are placed one after another, each
+/// with its required alignment.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::pvirtq;
+///
+/// let layout = pvirtq::layout(256);
+/// assert_eq!(layout.desc_offset, 0);
+/// assert_eq!(layout.driver_offset, 4096);
+/// assert_eq!(layout.device_offset, 4100);
+/// assert_eq!(layout.layout.size(), 4112);
+/// ```
+pub fn layout(queue_size: u16) -> QueueLayout {
+    let event_suppress = Layout::new::<EventSuppress>().align_to(4).unwrap();
+    let desc = DescRing::layout(queue_size);
+    let (layout, driver_offset) = desc.extend(event_suppress).unwrap();
+    let (layout, device_offset) = layout.extend(event_suppress).unwrap();
+
+    QueueLayout {
+        layout: layout.pad_to_align(),
+        desc_offset: 0,
+        driver_offset,
+        device_offset,
+    }
+}
+
 /// Event Suppression Descriptor
+///
+/// Use [`EventSuppressVolatileFieldAccess`] to work with this struct.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::pvirtq::EventSuppress;
+/// use virtio::RingEventFlags;
+///
+/// let event_suppress = EventSuppress::for_desc(3, true);
+/// assert_eq!(event_suppress.desc_event_flags(), RingEventFlags::Desc);
+/// assert_eq!(event_suppress.desc_event(), Some((3, true)));
+///
+/// let event_suppress = EventSuppress::new(RingEventFlags::Disable);
+/// assert_eq!(event_suppress.desc_event(), None);
+/// ```
 #[doc(alias = "pvirtq_event_suppress")]
+#[derive(VolatileFieldAccess, Clone, Copy, Debug)]
 #[repr(C)]
 pub struct EventSuppress {
     /// If desc_event_flags set to RING_EVENT_FLAGS_DESC
@@ -30,6 +387,44 @@ pub struct EventSuppress {
     pub flags: EventSuppressFlags,
 }
 
+impl EventSuppress {
+    /// Creates a new event suppression descriptor with the given flags.
+    pub const fn new(desc_event_flags: RingEventFlags) -> Self {
+        Self {
+            desc: EventSuppressDesc::new(),
+            flags: EventSuppressFlags::new().with_desc_event_flags(desc_event_flags),
+        }
+    }
+
+    /// Creates a new event suppression descriptor that enables events for
+    /// the descriptor at `desc_event_off` with the wrap counter `desc_event_wrap`.
+    ///
+    /// Only valid if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has been negotiated.
+    pub const fn for_desc(desc_event_off: u16, desc_event_wrap: bool) -> Self {
+        Self {
+            desc: EventSuppressDesc::new()
+                .with_desc_event_off(desc_event_off)
+                .with_desc_event_wrap(desc_event_wrap as u8),
+            flags: EventSuppressFlags::new().with_desc_event_flags(RingEventFlags::Desc),
+        }
+    }
+
+    /// Returns the descriptor ring change event flags.
+    pub const fn desc_event_flags(&self) -> RingEventFlags {
+        self.flags.desc_event_flags()
+    }
+
+    /// Returns the descriptor ring change event offset and wrap counter
+    /// if the flags are [`RING_EVENT_FLAGS_DESC`](RingEventFlags::Desc).
+    pub fn desc_event(&self) -> Option<(u16, bool)> {
+        if self.desc_event_flags() != RingEventFlags::Desc {
+            return None;
+        }
+
+        Some((self.desc.desc_event_off(), self.desc.desc_event_wrap() != 0))
+    }
+}
+
 /// Event Suppression Flags
 #[bitfield(u16, repr = le16, from = le16::from_ne, into = le16::to_ne)]
 pub struct EventSuppressDesc {
@@ -52,3 +447,84 @@ pub struct EventSuppressFlags {
     #[bits(14)]
     pub reserved: u16,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::virtq::DescF;
+
+    fn desc(flags: DescF, id: u16) -> Desc {
+        Desc {
+            addr: le64::from_ne(0),
+            len: le32::from_ne(0),
+            id: le16::from_ne(id),
+            flags,
+        }
+    }
+
+    #[test]
+    fn chain_wraparound() {
+        let ring = [
+            desc(DescF::NEXT, 0),
+            desc(DescF::empty(), 5),
+            desc(DescF::NEXT, 0),
+        ];
+
+        let mut chain = DescChain::new(&ring, 2);
+        assert_eq!(chain.id(), None);
+        assert_eq!(chain.by_ref().filter(Result::is_ok).count(), 3);
+        assert_eq!(chain.id(), Some(5));
+    }
+
+    #[test]
+    fn chain_loop() {
+        let ring = [desc(DescF::NEXT, 0), desc(DescF::NEXT, 0)];
+
+        let mut chain = DescChain::new(&ring, 0);
+        assert!(chain.next().unwrap().is_ok());
+        assert!(chain.next().unwrap().is_ok());
+        assert_eq!(chain.next().unwrap().unwrap_err(), DescChainError::Loop);
+        assert!(chain.next().is_none());
+        assert_eq!(chain.id(), None);
+    }
+
+    #[test]
+    fn ring_pos_wraparound() {
+        let mut avail = RingPos::new(4);
+        let mut used = RingPos::new(4);
+
+        avail.advance(3);
+        assert_eq!((avail.pos(), avail.wrap_counter()), (3, true));
+        let flags = avail.make_avail_flags();
+        assert!(!used.is_used(flags));
+
+        avail.advance(1);
+        assert_eq!((avail.pos(), avail.wrap_counter()), (0, false));
+        assert_eq!(avail.make_avail_flags(), DescF::USED);
+
+        // The device marks a descriptor as used by setting both flags to its wrap counter.
+        assert!(used.is_used(DescF::AVAIL | DescF::USED));
+        used.advance(5);
+        assert_eq!((used.pos(), used.wrap_counter()), (1, false));
+        assert!(!used.is_used(DescF::AVAIL | DescF::USED));
+        assert!(used.is_used(DescF::empty()));
+
+        used.advance(7);
+        assert_eq!((used.pos(), used.wrap_counter()), (0, false));
+    }
+
+    #[test]
+    fn needs_notify_wraparound() {
+        let mut next = RingPos::new(4);
+        next.advance(3);
+
+        // Adding descriptors at positions 3 and 0 wraps around.
+        next.advance(2);
+        assert_eq!((next.pos(), next.wrap_counter()), (1, false));
+
+        assert!(needs_notify(3, true, next, 2));
+        assert!(needs_notify(0, false, next, 2));
+        assert!(!needs_notify(1, false, next, 2));
+        assert!(!needs_notify(2, true, next, 2));
+    }
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+virtq::desc_table_alloc_impl!(DescRing);
+
+#[cfg(test)]
+mod tests {
+    use core::ptr;
+
+    use super::*;
+
+    #[test]
+    fn desc_ring_layout() {
+        for queue_size in [1, 255, 256, 257] {
+            let ring = DescRing::new(queue_size);
+            assert_eq!(ring.0.len(), usize::from(queue_size));
+            assert_eq!(ptr::addr_of!(*ring).cast::<u8>() as usize % 16, 0);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Entropy Device
+//!
+//! The entropy device supplies high-quality randomness for guest use.
+//! It uses a single virtqueue, `requestq`, in which the driver places device-writable buffers that the device fills with random bytes.
+//! The device has no device-specific feature bits and no device configuration layout.
+
+pub use super::features::rng::F;
@@ -0,0 +1,143 @@
+//! RPMB Device
+//!
+//! Unlike most other structures in this crate, the fields of [`Frame`] are big-endian, following the JEDEC eMMC RPMB frame format.
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::rpmb::F;
+use crate::{be16, be32};
+
+/// RPMB Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_rpmb_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The capacity of the device (expressed in 128KB units).
+    /// The values MUST range between 0x00 and 0x80 inclusive.
+    #[access(ReadOnly)]
+    capacity: u8,
+
+    /// The maximum number of RPMB block count that can be performed to device in one request.
+    /// 0 implies no limitation.
+    #[access(ReadOnly)]
+    max_wr_cnt: u8,
+
+    /// The maximum number of RPMB block count that can be performed to device in one request.
+    /// 0 implies no limitation.
+    #[access(ReadOnly)]
+    max_rd_cnt: u8,
+}
+
+/// Request Type
+#[doc(alias = "VIRTIO_RPMB_REQ")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Req {
+    #[doc(alias = "VIRTIO_RPMB_REQ_PROGRAM_KEY")]
+    ProgramKey = 0x0001,
+
+    #[doc(alias = "VIRTIO_RPMB_REQ_GET_WRITE_COUNTER")]
+    GetWriteCounter = 0x0002,
+
+    #[doc(alias = "VIRTIO_RPMB_REQ_DATA_WRITE")]
+    DataWrite = 0x0003,
+
+    #[doc(alias = "VIRTIO_RPMB_REQ_DATA_READ")]
+    DataRead = 0x0004,
+
+    #[doc(alias = "VIRTIO_RPMB_REQ_RESULT_READ")]
+    ResultRead = 0x0005,
+}
+
+/// Response Type
+#[doc(alias = "VIRTIO_RPMB_RESP")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Resp {
+    #[doc(alias = "VIRTIO_RPMB_RESP_PROGRAM_KEY")]
+    ProgramKey = 0x0100,
+
+    #[doc(alias = "VIRTIO_RPMB_RESP_GET_COUNTER")]
+    GetCounter = 0x0200,
+
+    #[doc(alias = "VIRTIO_RPMB_RESP_DATA_WRITE")]
+    DataWrite = 0x0300,
+
+    #[doc(alias = "VIRTIO_RPMB_RESP_DATA_READ")]
+    DataRead = 0x0400,
+}
+
+/// Operation Result
+#[doc(alias = "VIRTIO_RPMB_RES")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Res {
+    #[doc(alias = "VIRTIO_RPMB_RES_OK")]
+    Ok = 0x0000,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_GENERAL_FAILURE")]
+    GeneralFailure = 0x0001,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_AUTH_FAILURE")]
+    AuthFailure = 0x0002,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_COUNT_FAILURE")]
+    CountFailure = 0x0003,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_ADDR_FAILURE")]
+    AddrFailure = 0x0004,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_WRITE_FAILURE")]
+    WriteFailure = 0x0005,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_READ_FAILURE")]
+    ReadFailure = 0x0006,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_NO_AUTH_KEY")]
+    NoAuthKey = 0x0007,
+
+    #[doc(alias = "VIRTIO_RPMB_RES_WRITE_COUNTER_EXPIRED")]
+    WriteCounterExpired = 0x0080,
+}
+
+/// RPMB Frame
+#[doc(alias = "virtio_rpmb_frame")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Frame {
+    pub stuff: [u8; 196],
+    pub key_mac: [u8; 32],
+    pub data: [u8; 256],
+    pub nonce: [u8; 16],
+    pub write_counter: be32,
+    pub address: be16,
+    pub block_count: be16,
+    /// See [`Res`].
+    pub result: be16,
+    /// See [`Req`] and [`Resp`].
+    pub req_resp: be16,
+}
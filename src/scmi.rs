@@ -0,0 +1,7 @@
+//! SCMI Device
+//!
+//! The SCMI device transports messages of the Arm System Control and Management Interface (SCMI).
+//! It uses the virtqueue `cmdq` for commands and, if [`VIRTIO_SCMI_F_P2A_CHANNELS`](F::P2A_CHANNELS) is negotiated, the virtqueue `eventq` for delayed responses and notifications.
+//! The device has no device configuration layout.
+
+pub use super::features::scmi::F;
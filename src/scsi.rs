@@ -0,0 +1,399 @@
+//! SCSI Host Device
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::{ReadOnly, ReadWrite};
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::scsi::F;
+use crate::{le16, le32, le64};
+
+/// SCSI Host Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_scsi_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// This is the total number of request virtqueues exposed by
+    /// the device. The driver MAY use only one request queue,
+    /// or it can use more to achieve better performance.
+    #[access(ReadOnly)]
+    num_queues: le32,
+
+    /// This is a hint to the driver about the maximum number of
+    /// segments that can be in a command.
+    #[access(ReadOnly)]
+    seg_max: le32,
+
+    /// This is a hint to the driver about the maximum transfer
+    /// size to use.
+    #[access(ReadOnly)]
+    max_sectors: le32,
+
+    /// This is a hint to the driver about the maximum number of
+    /// linked commands the device will ever send.
+    #[access(ReadOnly)]
+    cmd_per_lun: le32,
+
+    /// This is the maximum size that the device will fill for
+    /// buffers that the driver places in the eventq.
+    #[access(ReadOnly)]
+    event_info_size: le32,
+
+    /// This is the maximum size of the sense data that the
+    /// device will write. The default value is written by the device and MUST be
+    /// 96, but the driver can modify it.
+    #[access(ReadWrite)]
+    sense_size: le32,
+
+    /// This is the maximum size of the CDB that the driver will
+    /// write. The default value is written by the device and MUST be 32, but the
+    /// driver can likewise modify it.
+    #[access(ReadWrite)]
+    cdb_size: le32,
+
+    /// The maximum channel number supported by the device.
+    #[access(ReadOnly)]
+    max_channel: le16,
+
+    /// The maximum target number supported by the device.
+    #[access(ReadOnly)]
+    max_target: le16,
+
+    /// The maximum LUN number supported by the device.
+    #[access(ReadOnly)]
+    max_lun: le32,
+}
+
+/// Default CDB size
+///
+/// The default value of [`Config`]'s `cdb_size`, which is the size of [`ReqCmd::cdb`].
+#[doc(alias = "VIRTIO_SCSI_CDB_SIZE")]
+pub const CDB_SIZE: usize = 32;
+
+/// Default sense size
+///
+/// The default value of [`Config`]'s `sense_size`, which is the size of [`RespCmd::sense`].
+#[doc(alias = "VIRTIO_SCSI_SENSE_SIZE")]
+pub const SENSE_SIZE: usize = 96;
+
+/// Command Request
+///
+/// This is the device-readable part of `virtio_scsi_req_cmd`.
+/// It uses the default [`CDB_SIZE`] and does not include the fields that are only present if [`VIRTIO_SCSI_F_T10_PI`](F::T10_PI) is negotiated.
+#[doc(alias = "virtio_scsi_req_cmd")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct ReqCmd {
+    pub lun: [u8; 8],
+    pub id: le64,
+    /// Task attribute, see [`TaskAttr`].
+    pub task_attr: u8,
+    pub prio: u8,
+    pub crn: u8,
+    pub cdb: [u8; CDB_SIZE],
+}
+
+le_bytes_impl! {
+    ReqCmd {
+        lun: [u8; 8],
+        id: le64,
+        task_attr: u8,
+        prio: u8,
+        crn: u8,
+        cdb: [u8; CDB_SIZE],
+    }
+}
+
+/// Command Response
+///
+/// This is the device-writable part of `virtio_scsi_req_cmd`.
+/// It uses the default [`SENSE_SIZE`] and does not include the fields that are only present if [`VIRTIO_SCSI_F_T10_PI`](F::T10_PI) is negotiated.
+#[doc(alias = "virtio_scsi_req_cmd")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespCmd {
+    pub sense_len: le32,
+    pub residual: le32,
+    pub status_qualifier: le16,
+    pub status: u8,
+    /// Response, see [`S`].
+    pub response: u8,
+    pub sense: [u8; SENSE_SIZE],
+}
+
+le_bytes_impl! {
+    RespCmd {
+        sense_len: le32,
+        residual: le32,
+        status_qualifier: le16,
+        status: u8,
+        response: u8,
+        sense: [u8; SENSE_SIZE],
+    }
+}
+
+/// Task Attribute
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum TaskAttr {
+    #[doc(alias = "VIRTIO_SCSI_S_SIMPLE")]
+    Simple = 0,
+
+    #[doc(alias = "VIRTIO_SCSI_S_ORDERED")]
+    Ordered = 1,
+
+    #[doc(alias = "VIRTIO_SCSI_S_HEAD")]
+    Head = 2,
+
+    #[doc(alias = "VIRTIO_SCSI_S_ACA")]
+    Aca = 3,
+}
+
+/// Response Codes
+#[doc(alias = "VIRTIO_SCSI_S")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum S {
+    #[doc(alias = "VIRTIO_SCSI_S_OK")]
+    #[doc(alias = "VIRTIO_SCSI_S_FUNCTION_COMPLETE")]
+    Ok = 0,
+
+    #[doc(alias = "VIRTIO_SCSI_S_OVERRUN")]
+    Overrun = 1,
+
+    #[doc(alias = "VIRTIO_SCSI_S_ABORTED")]
+    Aborted = 2,
+
+    #[doc(alias = "VIRTIO_SCSI_S_BAD_TARGET")]
+    BadTarget = 3,
+
+    #[doc(alias = "VIRTIO_SCSI_S_RESET")]
+    Reset = 4,
+
+    #[doc(alias = "VIRTIO_SCSI_S_BUSY")]
+    Busy = 5,
+
+    #[doc(alias = "VIRTIO_SCSI_S_TRANSPORT_FAILURE")]
+    TransportFailure = 6,
+
+    #[doc(alias = "VIRTIO_SCSI_S_TARGET_FAILURE")]
+    TargetFailure = 7,
+
+    #[doc(alias = "VIRTIO_SCSI_S_NEXUS_FAILURE")]
+    NexusFailure = 8,
+
+    #[doc(alias = "VIRTIO_SCSI_S_FAILURE")]
+    Failure = 9,
+
+    #[doc(alias = "VIRTIO_SCSI_S_FUNCTION_SUCCEEDED")]
+    FunctionSucceeded = 10,
+
+    #[doc(alias = "VIRTIO_SCSI_S_FUNCTION_REJECTED")]
+    FunctionRejected = 11,
+
+    #[doc(alias = "VIRTIO_SCSI_S_INCORRECT_LUN")]
+    IncorrectLun = 12,
+}
+
+/// Control Request Type
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum CtrlType {
+    /// Task management function
+    #[doc(alias = "VIRTIO_SCSI_T_TMF")]
+    Tmf = 0,
+
+    /// Asynchronous notification query
+    #[doc(alias = "VIRTIO_SCSI_T_AN_QUERY")]
+    AnQuery = 1,
+
+    /// Asynchronous notification subscription
+    #[doc(alias = "VIRTIO_SCSI_T_AN_SUBSCRIBE")]
+    AnSubscribe = 2,
+}
+
+/// Task Management Function Subtype
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum TmfSubtype {
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_ABORT_TASK")]
+    AbortTask = 0,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_ABORT_TASK_SET")]
+    AbortTaskSet = 1,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_CLEAR_ACA")]
+    ClearAca = 2,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_CLEAR_TASK_SET")]
+    ClearTaskSet = 3,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_I_T_NEXUS_RESET")]
+    ITNexusReset = 4,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_LOGICAL_UNIT_RESET")]
+    LogicalUnitReset = 5,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_QUERY_TASK")]
+    QueryTask = 6,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TMF_QUERY_TASK_SET")]
+    QueryTaskSet = 7,
+}
+
+/// Task Management Function Request
+///
+/// This is the device-readable part of `virtio_scsi_ctrl_tmf`.
+#[doc(alias = "virtio_scsi_ctrl_tmf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlTmfReq {
+    /// [`VIRTIO_SCSI_T_TMF`](CtrlType::Tmf)
+    pub type_: le32,
+    /// See [`TmfSubtype`].
+    pub subtype: le32,
+    pub lun: [u8; 8],
+    pub id: le64,
+}
+
+le_bytes_impl! {
+    CtrlTmfReq {
+        type_: le32,
+        subtype: le32,
+        lun: [u8; 8],
+        id: le64,
+    }
+}
+
+/// Task Management Function Response
+///
+/// This is the device-writable part of `virtio_scsi_ctrl_tmf`.
+#[doc(alias = "virtio_scsi_ctrl_tmf")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CtrlTmfResp {
+    /// Response, see [`S`].
+    pub response: u8,
+}
+
+le_bytes_impl! {
+    CtrlTmfResp {
+        response: u8,
+    }
+}
+
+/// Event Type
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum EventType {
+    #[doc(alias = "VIRTIO_SCSI_T_NO_EVENT")]
+    NoEvent = 0,
+
+    #[doc(alias = "VIRTIO_SCSI_T_TRANSPORT_RESET")]
+    TransportReset = 1,
+
+    #[doc(alias = "VIRTIO_SCSI_T_ASYNC_NOTIFY")]
+    AsyncNotify = 2,
+
+    #[doc(alias = "VIRTIO_SCSI_T_PARAM_CHANGE")]
+    ParamChange = 3,
+}
+
+/// Events Missed
+///
+/// If bit 31 is set in the `event` field of [`Event`], the device failed to report an event due to missing buffers.
+#[doc(alias = "VIRTIO_SCSI_T_EVENTS_MISSED")]
+pub const EVENTS_MISSED: u32 = 0x80000000;
+
+/// Transport Reset Reason
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum ResetReason {
+    #[doc(alias = "VIRTIO_SCSI_EVT_RESET_HARD")]
+    Hard = 0,
+
+    #[doc(alias = "VIRTIO_SCSI_EVT_RESET_RESCAN")]
+    Rescan = 1,
+
+    #[doc(alias = "VIRTIO_SCSI_EVT_RESET_REMOVED")]
+    Removed = 2,
+}
+
+/// Event
+#[doc(alias = "virtio_scsi_event")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Event {
+    /// See [`EventType`] and [`EVENTS_MISSED`].
+    pub event: le32,
+    pub lun: [u8; 8],
+    pub reason: le32,
+}
+
+le_bytes_impl! {
+    Event {
+        event: le32,
+        lun: [u8; 8],
+        reason: le32,
+    }
+}
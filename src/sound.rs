@@ -0,0 +1,637 @@
+//! Sound Device
+
+use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::sound::F;
+use crate::{le32, le64};
+
+/// Sound Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_snd_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The total number of all available jacks.
+    #[access(ReadOnly)]
+    jacks: le32,
+
+    /// The total number of all available PCM streams.
+    #[access(ReadOnly)]
+    streams: le32,
+
+    /// The total number of all available channel maps.
+    #[access(ReadOnly)]
+    chmaps: le32,
+}
+
+/// Request Type
+#[doc(alias = "VIRTIO_SND_R")]
+#[derive(IntoPrimitive, FromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum R {
+    #[doc(alias = "VIRTIO_SND_R_JACK_INFO")]
+    JackInfo = 1,
+
+    #[doc(alias = "VIRTIO_SND_R_JACK_REMAP")]
+    JackRemap = 2,
+
+    #[doc(alias = "VIRTIO_SND_R_PCM_INFO")]
+    PcmInfo = 0x0100,
+
+    #[doc(alias = "VIRTIO_SND_R_PCM_SET_PARAMS")]
+    PcmSetParams = 0x0101,
+
+    #[doc(alias = "VIRTIO_SND_R_PCM_PREPARE")]
+    PcmPrepare = 0x0102,
+
+    #[doc(alias = "VIRTIO_SND_R_PCM_RELEASE")]
+    PcmRelease = 0x0103,
+
+    #[doc(alias = "VIRTIO_SND_R_PCM_START")]
+    PcmStart = 0x0104,
+
+    #[doc(alias = "VIRTIO_SND_R_PCM_STOP")]
+    PcmStop = 0x0105,
+
+    #[doc(alias = "VIRTIO_SND_R_CHMAP_INFO")]
+    ChmapInfo = 0x0200,
+
+    /// Unknown request type
+    #[num_enum(catch_all)]
+    Unknown(u32),
+}
+
+/// Event Type
+#[doc(alias = "VIRTIO_SND_EVT")]
+#[derive(IntoPrimitive, FromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Evt {
+    /// An external device has been connected to the jack.
+    #[doc(alias = "VIRTIO_SND_EVT_JACK_CONNECTED")]
+    JackConnected = 0x1000,
+
+    /// An external device has been disconnected from the jack.
+    #[doc(alias = "VIRTIO_SND_EVT_JACK_DISCONNECTED")]
+    JackDisconnected = 0x1001,
+
+    /// A hardware buffer period has elapsed, the period size is controlled using the `period_bytes` field.
+    #[doc(alias = "VIRTIO_SND_EVT_PCM_PERIOD_ELAPSED")]
+    PcmPeriodElapsed = 0x1100,
+
+    /// An underflow for the output stream or an overflow for the input stream has occurred.
+    #[doc(alias = "VIRTIO_SND_EVT_PCM_XRUN")]
+    PcmXrun = 0x1101,
+
+    /// Unknown event type
+    #[num_enum(catch_all)]
+    Unknown(u32),
+}
+
+/// Status Code
+#[doc(alias = "VIRTIO_SND_S")]
+#[derive(IntoPrimitive, FromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum S {
+    /// success
+    #[doc(alias = "VIRTIO_SND_S_OK")]
+    Ok = 0x8000,
+
+    /// a control message is malformed or contains invalid parameters
+    #[doc(alias = "VIRTIO_SND_S_BAD_MSG")]
+    BadMsg = 0x8001,
+
+    /// requested operation or parameters are not supported
+    #[doc(alias = "VIRTIO_SND_S_NOT_SUPP")]
+    NotSupp = 0x8002,
+
+    /// an I/O error occurred
+    #[doc(alias = "VIRTIO_SND_S_IO_ERR")]
+    IoErr = 0x8003,
+
+    /// Unknown status code
+    #[num_enum(catch_all)]
+    Unknown(u32),
+}
+
+/// Data Flow Direction
+#[doc(alias = "VIRTIO_SND_D")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum D {
+    #[doc(alias = "VIRTIO_SND_D_OUTPUT")]
+    Output = 0,
+
+    #[doc(alias = "VIRTIO_SND_D_INPUT")]
+    Input = 1,
+}
+
+/// Common Header
+#[doc(alias = "virtio_snd_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Hdr {
+    /// Specifies a device request type ([`R`]), an event type ([`Evt`]) or a status code ([`S`]).
+    pub code: le32,
+}
+
+le_bytes_impl! {
+    Hdr {
+        code: le32,
+    }
+}
+
+/// Event Notification
+#[doc(alias = "virtio_snd_event")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Event {
+    /// Indicates an event type ([`Evt`]).
+    pub hdr: Hdr,
+    /// Indicates an optional event data.
+    pub data: le32,
+}
+
+/// Item Information Request
+#[doc(alias = "virtio_snd_query_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct QueryInfo {
+    /// Specifies a particular item request type ([`R`]).
+    pub hdr: Hdr,
+    /// Specifies the starting identifier for the item.
+    pub start_id: le32,
+    /// Specifies the number of items for which information is requested.
+    pub count: le32,
+    /// Specifies the size of the structure containing information for one item.
+    pub size: le32,
+}
+
+/// Common Item Information Header
+#[doc(alias = "virtio_snd_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Info {
+    /// Specifies a function group node identifier.
+    pub hda_fn_nid: le32,
+}
+
+/// Jack Control Request Header
+#[doc(alias = "virtio_snd_jack_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct JackHdr {
+    /// Specifies a particular jack request type ([`R`]).
+    pub hdr: Hdr,
+    /// Specifies a jack identifier from 0 to `jacks` - 1.
+    pub jack_id: le32,
+}
+
+endian_bitflags! {
+    /// Jack Features
+    #[doc(alias = "VIRTIO_SND_JACK_F")]
+    pub struct JackF: le32 {
+        /// Jack remapping support.
+        #[doc(alias = "VIRTIO_SND_JACK_F_REMAP")]
+        const REMAP = 1 << 0;
+    }
+}
+
+/// Jack Information
+#[doc(alias = "virtio_snd_jack_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct JackInfo {
+    pub hdr: Info,
+    /// Specifies a supported feature bit map.
+    pub features: JackF,
+    /// Indicates a pin default configuration value.
+    pub hda_reg_defconf: le32,
+    /// Indicates a pin capabilities value.
+    pub hda_reg_caps: le32,
+    /// Indicates the current jack connection status (1 - connected, 0 - disconnected).
+    pub connected: u8,
+    pub padding: [u8; 7],
+}
+
+/// Jack Remap Request
+#[doc(alias = "virtio_snd_jack_remap")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct JackRemap {
+    /// `.code` = [`VIRTIO_SND_R_JACK_REMAP`](R::JackRemap)
+    pub hdr: JackHdr,
+    /// Specifies the selected association number.
+    pub association: le32,
+    /// Specifies the selected sequence number.
+    pub sequence: le32,
+}
+
+/// PCM Control Request Header
+#[doc(alias = "virtio_snd_pcm_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PcmHdr {
+    /// Specifies a particular PCM request type ([`R`]).
+    pub hdr: Hdr,
+    /// Specifies a PCM stream identifier from 0 to `streams` - 1.
+    pub stream_id: le32,
+}
+
+endian_bitflags! {
+    /// PCM Stream Features
+    #[doc(alias = "VIRTIO_SND_PCM_F")]
+    pub struct PcmF: le32 {
+        /// Supports sharing a host memory with a guest.
+        #[doc(alias = "VIRTIO_SND_PCM_F_SHMEM_HOST")]
+        const SHMEM_HOST = 1 << 0;
+
+        /// Supports sharing a guest memory with a host.
+        #[doc(alias = "VIRTIO_SND_PCM_F_SHMEM_GUEST")]
+        const SHMEM_GUEST = 1 << 1;
+
+        /// Supports polling mode for message-based transport.
+        #[doc(alias = "VIRTIO_SND_PCM_F_MSG_POLLING")]
+        const MSG_POLLING = 1 << 2;
+
+        /// Supports elapsed period notifications for shared memory transport.
+        #[doc(alias = "VIRTIO_SND_PCM_F_EVT_SHMEM_PERIODS")]
+        const EVT_SHMEM_PERIODS = 1 << 3;
+
+        /// Supports underrun/overrun notifications.
+        #[doc(alias = "VIRTIO_SND_PCM_F_EVT_XRUNS")]
+        const EVT_XRUNS = 1 << 4;
+    }
+}
+
+/// PCM Sample Format
+///
+/// In [`PcmInfo::formats`], each format corresponds to bit `1 << format`.
+#[doc(alias = "VIRTIO_SND_PCM_FMT")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum PcmFmt {
+    /// 4 / 4 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_IMA_ADPCM")]
+    ImaAdpcm = 0,
+
+    /// 8 / 8 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_MU_LAW")]
+    MuLaw = 1,
+
+    /// 8 / 8 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_A_LAW")]
+    ALaw = 2,
+
+    /// 8 / 8 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S8")]
+    S8 = 3,
+
+    /// 8 / 8 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U8")]
+    U8 = 4,
+
+    /// 16 / 16 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S16")]
+    S16 = 5,
+
+    /// 16 / 16 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U16")]
+    U16 = 6,
+
+    /// 18 / 24 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S18_3")]
+    S18_3 = 7,
+
+    /// 18 / 24 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U18_3")]
+    U18_3 = 8,
+
+    /// 20 / 24 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S20_3")]
+    S20_3 = 9,
+
+    /// 20 / 24 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U20_3")]
+    U20_3 = 10,
+
+    /// 24 / 24 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S24_3")]
+    S24_3 = 11,
+
+    /// 24 / 24 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U24_3")]
+    U24_3 = 12,
+
+    /// 20 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S20")]
+    S20 = 13,
+
+    /// 20 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U20")]
+    U20 = 14,
+
+    /// 24 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S24")]
+    S24 = 15,
+
+    /// 24 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U24")]
+    U24 = 16,
+
+    /// 32 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_S32")]
+    S32 = 17,
+
+    /// 32 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_U32")]
+    U32 = 18,
+
+    /// 32 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_FLOAT")]
+    Float = 19,
+
+    /// 64 / 64 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_FLOAT64")]
+    Float64 = 20,
+
+    /// 8 / 8 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_DSD_U8")]
+    DsdU8 = 21,
+
+    /// 16 / 16 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_DSD_U16")]
+    DsdU16 = 22,
+
+    /// 32 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_DSD_U32")]
+    DsdU32 = 23,
+
+    /// 32 / 32 bits
+    #[doc(alias = "VIRTIO_SND_PCM_FMT_IEC958_SUBFRAME")]
+    Iec958Subframe = 24,
+}
+
+/// PCM Frame Rate
+///
+/// In [`PcmInfo::rates`], each rate corresponds to bit `1 << rate`.
+#[doc(alias = "VIRTIO_SND_PCM_RATE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum PcmRate {
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_5512")]
+    Rate5512 = 0,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_8000")]
+    Rate8000 = 1,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_11025")]
+    Rate11025 = 2,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_16000")]
+    Rate16000 = 3,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_22050")]
+    Rate22050 = 4,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_32000")]
+    Rate32000 = 5,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_44100")]
+    Rate44100 = 6,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_48000")]
+    Rate48000 = 7,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_64000")]
+    Rate64000 = 8,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_88200")]
+    Rate88200 = 9,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_96000")]
+    Rate96000 = 10,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_176400")]
+    Rate176400 = 11,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_192000")]
+    Rate192000 = 12,
+
+    #[doc(alias = "VIRTIO_SND_PCM_RATE_384000")]
+    Rate384000 = 13,
+}
+
+/// PCM Stream Information
+#[doc(alias = "virtio_snd_pcm_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PcmInfo {
+    pub hdr: Info,
+    /// Specifies a supported feature bit map.
+    pub features: PcmF,
+    /// Specifies a supported sample format bit map ([`PcmFmt`]).
+    pub formats: le64,
+    /// Specifies a supported frame rate bit map ([`PcmRate`]).
+    pub rates: le64,
+    /// Indicates the direction of data flow ([`D`]).
+    pub direction: u8,
+    /// Indicates a minimum number of supported channels.
+    pub channels_min: u8,
+    /// Indicates a maximum number of supported channels.
+    pub channels_max: u8,
+    pub padding: [u8; 5],
+}
+
+/// PCM Set Parameters Request
+#[doc(alias = "virtio_snd_pcm_set_params")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PcmSetParams {
+    /// `.code` = [`VIRTIO_SND_R_PCM_SET_PARAMS`](R::PcmSetParams)
+    pub hdr: PcmHdr,
+    /// Specifies the size of the hardware buffer used by the driver.
+    pub buffer_bytes: le32,
+    /// Specifies the size of the hardware period used by the driver.
+    pub period_bytes: le32,
+    /// Specifies a selected feature bit map.
+    pub features: PcmF,
+    /// Specifies a selected number of channels.
+    pub channels: u8,
+    /// Specifies a selected sample format ([`PcmFmt`]).
+    pub format: u8,
+    /// Specifies a selected frame rate ([`PcmRate`]).
+    pub rate: u8,
+    pub padding: u8,
+}
+
+/// PCM I/O Message Header
+#[doc(alias = "virtio_snd_pcm_xfer")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PcmXfer {
+    /// Specifies a PCM stream identifier from 0 to `streams` - 1.
+    pub stream_id: le32,
+}
+
+/// PCM I/O Message Status
+#[doc(alias = "virtio_snd_pcm_status")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PcmStatus {
+    /// Contains VIRTIO_SND_S_OK if an operation is successful, and VIRTIO_SND_S_IO_ERR otherwise ([`S`]).
+    pub status: le32,
+    /// Indicates the current device latency.
+    pub latency_bytes: le32,
+}
+
+/// Maximum number of channel positions in a channel map
+#[doc(alias = "VIRTIO_SND_CHMAP_MAX_SIZE")]
+pub const CHMAP_MAX_SIZE: usize = 18;
+
+/// Channel Map Information
+#[doc(alias = "virtio_snd_chmap_info")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ChmapInfo {
+    pub hdr: Info,
+    /// Indicates the direction of data flow ([`D`]).
+    pub direction: u8,
+    /// Indicates the number of valid channel position values.
+    pub channels: u8,
+    /// Contains VIRTIO_SND_CHMAP_XXX channel position values.
+    pub positions: [u8; CHMAP_MAX_SIZE],
+}
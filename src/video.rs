@@ -0,0 +1,455 @@
+//! Video Encoder and Decoder Devices
+//!
+//! <div class="warning">
+//!
+//! These devices are not part of a released VIRTIO specification yet.
+//! These definitions follow the virtio-video proposal (version 3) and may change.
+//!
+//! </div>
+//!
+//! The encoder ([`Id::VideoEncoder`]) and decoder ([`Id::VideoDecoder`]) devices share these definitions.
+//! Both have a `commandq` and an `eventq`.
+//!
+//! Each request on the `commandq` starts with a [`CmdHdr`] and each response starts with a [`RespHdr`].
+//! Commands without a dedicated response struct are answered with a plain [`RespHdr`].
+//!
+//! [`Id::VideoEncoder`]: crate::Id::VideoEncoder
+//! [`Id::VideoDecoder`]: crate::Id::VideoDecoder
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use volatile::access::ReadOnly;
+use volatile_macro::VolatileFieldAccess;
+
+pub use super::features::video::F;
+use crate::{le32, le64};
+
+/// Video Device Configuration Layout
+///
+/// Use [`ConfigVolatileFieldAccess`] to work with this struct.
+#[doc(alias = "virtio_video_config")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+    )
+)]
+#[derive(VolatileFieldAccess)]
+#[repr(C)]
+pub struct Config {
+    /// The protocol version that the device supports.
+    #[access(ReadOnly)]
+    version: le32,
+
+    /// The maximum length in bytes of a [`VIRTIO_VIDEO_CMD_QUERY_CAPABILITY`](Cmd::QueryCapability) response.
+    #[access(ReadOnly)]
+    max_caps_length: le32,
+
+    /// The maximum length in bytes of any other response.
+    #[access(ReadOnly)]
+    max_resp_length: le32,
+}
+
+/// Command Type
+#[doc(alias = "VIRTIO_VIDEO_CMD")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Cmd {
+    #[doc(alias = "VIRTIO_VIDEO_CMD_QUERY_CAPABILITY")]
+    QueryCapability = 0x0100,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_STREAM_CREATE")]
+    StreamCreate,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_STREAM_DESTROY")]
+    StreamDestroy,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_STREAM_DRAIN")]
+    StreamDrain,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_RESOURCE_CREATE")]
+    ResourceCreate,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_RESOURCE_QUEUE")]
+    ResourceQueue,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_RESOURCE_DESTROY_ALL")]
+    ResourceDestroyAll,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_QUEUE_CLEAR")]
+    QueueClear,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_GET_PARAMS")]
+    GetParams,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_SET_PARAMS")]
+    SetParams,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_QUERY_CONTROL")]
+    QueryControl,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_GET_CONTROL")]
+    GetControl,
+
+    #[doc(alias = "VIRTIO_VIDEO_CMD_SET_CONTROL")]
+    SetControl,
+}
+
+/// Response Type
+#[doc(alias = "VIRTIO_VIDEO_RESP")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum Resp {
+    #[doc(alias = "VIRTIO_VIDEO_RESP_OK_NODATA")]
+    OkNodata = 0x0200,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_OK_QUERY_CAPABILITY")]
+    OkQueryCapability,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_OK_RESOURCE_QUEUE")]
+    OkResourceQueue,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_OK_GET_PARAMS")]
+    OkGetParams,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_OK_QUERY_CONTROL")]
+    OkQueryControl,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_OK_GET_CONTROL")]
+    OkGetControl,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_ERR_INVALID_OPERATION")]
+    ErrInvalidOperation = 0x0300,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_ERR_OUT_OF_MEMORY")]
+    ErrOutOfMemory,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_ERR_INVALID_STREAM_ID")]
+    ErrInvalidStreamId,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_ERR_INVALID_RESOURCE_ID")]
+    ErrInvalidResourceId,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_ERR_INVALID_PARAMETER")]
+    ErrInvalidParameter,
+
+    #[doc(alias = "VIRTIO_VIDEO_RESP_ERR_UNSUPPORTED_CONTROL")]
+    ErrUnsupportedControl,
+}
+
+/// Command Header
+#[doc(alias = "virtio_video_cmd_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CmdHdr {
+    /// See [`Cmd`].
+    pub type_: le32,
+    pub stream_id: le32,
+}
+
+le_bytes_impl! {
+    CmdHdr {
+        type_: le32,
+        stream_id: le32,
+    }
+}
+
+/// Response Header
+#[doc(alias = "virtio_video_resp_hdr")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RespHdr {
+    /// See [`Resp`].
+    pub type_: le32,
+    pub stream_id: le32,
+}
+
+le_bytes_impl! {
+    RespHdr {
+        type_: le32,
+        stream_id: le32,
+    }
+}
+
+/// Queue Type
+#[doc(alias = "VIRTIO_VIDEO_QUEUE_TYPE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum QueueType {
+    #[doc(alias = "VIRTIO_VIDEO_QUEUE_TYPE_INPUT")]
+    Input = 0x0100,
+
+    #[doc(alias = "VIRTIO_VIDEO_QUEUE_TYPE_OUTPUT")]
+    Output,
+}
+
+/// Memory Type
+#[doc(alias = "VIRTIO_VIDEO_MEM_TYPE")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum MemType {
+    /// Resources are backed by guest pages described by [`MemEntry`]s.
+    #[doc(alias = "VIRTIO_VIDEO_MEM_TYPE_GUEST_PAGES")]
+    GuestPages = 0,
+
+    /// Resources are backed by virtio objects described by [`ObjectEntry`]s.
+    #[doc(alias = "VIRTIO_VIDEO_MEM_TYPE_VIRTIO_OBJECT")]
+    VirtioObject,
+}
+
+/// [`VIRTIO_VIDEO_CMD_STREAM_CREATE`](Cmd::StreamCreate) Request
+#[doc(alias = "virtio_video_stream_create")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct StreamCreate {
+    pub hdr: CmdHdr,
+    /// See [`MemType`].
+    pub in_mem_type: le32,
+    /// See [`MemType`].
+    pub out_mem_type: le32,
+    pub coded_format: le32,
+    pub padding: [u8; 4],
+    /// A human-readable name of the stream.
+    pub tag: [u8; 64],
+}
+
+/// Maximum number of planes
+#[doc(alias = "VIRTIO_VIDEO_MAX_PLANES")]
+pub const MAX_PLANES: usize = 8;
+
+endian_bitflags! {
+    /// Planes Layout
+    #[doc(alias = "VIRTIO_VIDEO_PLANES_LAYOUT")]
+    pub struct PlanesLayout: le32 {
+        /// All planes are stored in a single buffer.
+        #[doc(alias = "VIRTIO_VIDEO_PLANES_LAYOUT_SINGLE_BUFFER")]
+        const SINGLE_BUFFER = 1 << 0;
+
+        /// Each plane is stored in a separate buffer.
+        #[doc(alias = "VIRTIO_VIDEO_PLANES_LAYOUT_PER_PLANE")]
+        const PER_PLANE = 1 << 1;
+    }
+}
+
+/// Guest Memory Entry
+///
+/// This is the backing memory of resources with [`MemType::GuestPages`].
+#[doc(alias = "virtio_video_mem_entry")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct MemEntry {
+    pub addr: le64,
+    pub length: le32,
+    pub padding: [u8; 4],
+}
+
+/// Virtio Object Entry
+///
+/// This is the backing memory of resources with [`MemType::VirtioObject`].
+#[doc(alias = "virtio_video_object_entry")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ObjectEntry {
+    pub uuid: [u8; 16],
+}
+
+/// [`VIRTIO_VIDEO_CMD_RESOURCE_CREATE`](Cmd::ResourceCreate) Request
+///
+/// This is followed by the [`MemEntry`]s or [`ObjectEntry`]s of all planes,
+/// depending on the memory type of the queue.
+#[doc(alias = "virtio_video_resource_create")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceCreate {
+    pub hdr: CmdHdr,
+    /// See [`QueueType`].
+    pub queue_type: le32,
+    pub resource_id: le32,
+    pub planes_layout: PlanesLayout,
+    pub num_planes: le32,
+    pub plane_offsets: [le32; MAX_PLANES],
+    /// The number of memory entries of each plane.
+    pub num_entries: [le32; MAX_PLANES],
+}
+
+/// [`VIRTIO_VIDEO_CMD_RESOURCE_QUEUE`](Cmd::ResourceQueue) Request
+#[doc(alias = "virtio_video_resource_queue")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceQueue {
+    pub hdr: CmdHdr,
+    /// See [`QueueType`].
+    pub queue_type: le32,
+    pub resource_id: le32,
+    pub timestamp: le64,
+    pub num_data_sizes: le32,
+    pub data_sizes: [le32; MAX_PLANES],
+    pub padding: [u8; 4],
+}
+
+endian_bitflags! {
+    /// Buffer Flags
+    #[doc(alias = "VIRTIO_VIDEO_BUFFER_FLAG")]
+    pub struct BufferF: le32 {
+        #[doc(alias = "VIRTIO_VIDEO_BUFFER_FLAG_ERR")]
+        const ERR = 0x0001;
+
+        #[doc(alias = "VIRTIO_VIDEO_BUFFER_FLAG_EOS")]
+        const EOS = 0x0002;
+
+        /// The buffer contains an I-frame (encoder only).
+        #[doc(alias = "VIRTIO_VIDEO_BUFFER_FLAG_IFRAME")]
+        const IFRAME = 0x0004;
+
+        /// The buffer contains a P-frame (encoder only).
+        #[doc(alias = "VIRTIO_VIDEO_BUFFER_FLAG_PFRAME")]
+        const PFRAME = 0x0008;
+
+        /// The buffer contains a B-frame (encoder only).
+        #[doc(alias = "VIRTIO_VIDEO_BUFFER_FLAG_BFRAME")]
+        const BFRAME = 0x0010;
+    }
+}
+
+/// [`VIRTIO_VIDEO_RESP_OK_RESOURCE_QUEUE`](Resp::OkResourceQueue) Response
+#[doc(alias = "virtio_video_resource_queue_resp")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ResourceQueueResp {
+    pub hdr: RespHdr,
+    pub timestamp: le64,
+    pub flags: BufferF,
+    /// The size of the encoded data.
+    pub size: le32,
+}
+
+/// [`VIRTIO_VIDEO_CMD_QUEUE_CLEAR`](Cmd::QueueClear) Request
+#[doc(alias = "virtio_video_queue_clear")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct QueueClear {
+    pub hdr: CmdHdr,
+    /// See [`QueueType`].
+    pub queue_type: le32,
+    pub padding: [u8; 4],
+}
+
+/// Event Type
+#[doc(alias = "VIRTIO_VIDEO_EVENT")]
+#[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+#[repr(u32)]
+pub enum EventType {
+    /// An unrecoverable error occurred on the stream.
+    #[doc(alias = "VIRTIO_VIDEO_EVENT_ERROR")]
+    Error = 0x0100,
+
+    /// The decoder detected a change of the stream resolution.
+    #[doc(alias = "VIRTIO_VIDEO_EVENT_DECODER_RESOLUTION_CHANGED")]
+    DecoderResolutionChanged = 0x0200,
+}
+
+/// Event
+#[doc(alias = "virtio_video_event")]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy_derive::KnownLayout,
+        zerocopy_derive::Immutable,
+        zerocopy_derive::FromBytes,
+        zerocopy_derive::IntoBytes,
+    )
+)]
+#[derive(Default, Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Event {
+    /// See [`EventType`].
+    pub event_type: le32,
+    pub stream_id: le32,
+}
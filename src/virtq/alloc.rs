@@ -4,6 +4,58 @@ use allocator_api2::boxed::Box;
 
 use super::*;
 
+/// Implements allocating constructors for a descriptor table newtype.
+///
+/// This requires `layout` and `from_ptr` from [`desc_table_impl`](super::desc_table_impl).
+macro_rules! desc_table_alloc_impl {
+    ($Table:ident) => {
+        impl $Table {
+            pub fn new(queue_size: u16) -> ::allocator_api2::boxed::Box<Self> {
+                Self::new_in(queue_size, ::allocator_api2::alloc::Global)
+            }
+
+            pub fn try_new(
+                queue_size: u16,
+            ) -> Result<::allocator_api2::boxed::Box<Self>, ::allocator_api2::alloc::AllocError>
+            {
+                Self::try_new_in(queue_size, ::allocator_api2::alloc::Global)
+            }
+
+            pub fn new_in<A: ::allocator_api2::alloc::Allocator>(
+                queue_size: u16,
+                alloc: A,
+            ) -> ::allocator_api2::boxed::Box<Self, A> {
+                Self::try_new_in(queue_size, alloc).unwrap_or_else(|_| {
+                    ::alloc::alloc::handle_alloc_error(Self::layout(queue_size))
+                })
+            }
+
+            pub fn try_new_in<A: ::allocator_api2::alloc::Allocator>(
+                queue_size: u16,
+                alloc: A,
+            ) -> Result<::allocator_api2::boxed::Box<Self, A>, ::allocator_api2::alloc::AllocError>
+            {
+                let layout = Self::layout(queue_size);
+
+                let mem = alloc.allocate_zeroed(layout)?;
+                let mem = ::core::ptr::NonNull::slice_from_raw_parts(mem.cast(), layout.size());
+                let raw = Self::from_ptr(mem).unwrap();
+                let boxed =
+                    unsafe { ::allocator_api2::boxed::Box::from_raw_in(raw.as_ptr(), alloc) };
+
+                debug_assert_eq!(::core::alloc::Layout::for_value(&*boxed), layout);
+                debug_assert_eq!(boxed.0.len(), queue_size.into());
+
+                Ok(boxed)
+            }
+        }
+    };
+}
+
+pub(crate) use desc_table_alloc_impl;
+
+desc_table_alloc_impl!(DescTable);
+
 impl Avail {
     pub fn new(queue_size: u16, has_event_idx: bool) -> Box<Self> {
         Self::new_in(queue_size, has_event_idx, Global)
@@ -74,14 +126,31 @@ impl Used {
 
 #[cfg(test)]
 mod tests {
+    use core::ptr;
+
     use super::*;
 
+    #[test]
+    fn desc_table_layout() {
+        for queue_size in [0, 1, 255, 256, 257] {
+            let table = DescTable::new(queue_size);
+            assert_eq!(table.0.len(), usize::from(queue_size));
+            assert_eq!(ptr::addr_of!(*table).cast::<u8>() as usize % 16, 0);
+        }
+    }
+
     #[test]
     fn avail_layout() {
         for queue_size in [255, 256, 257] {
             for has_event_idx in [false, true] {
                 Avail::new(queue_size, has_event_idx);
             }
+
+            let avail = Avail::new(queue_size, true);
+            let base = ptr::addr_of!(*avail).cast::<u8>();
+            let used_event = ptr::from_ref(avail.used_event(true).unwrap()).cast::<u8>();
+            let offset = unsafe { used_event.offset_from(base) } as usize;
+            assert_eq!(offset, Avail::used_event_offset(queue_size));
         }
     }
 
@@ -91,6 +160,12 @@ mod tests {
             for has_event_idx in [false, true] {
                 Used::new(queue_size, has_event_idx);
             }
+
+            let used = Used::new(queue_size, true);
+            let base = ptr::addr_of!(*used).cast::<u8>();
+            let avail_event = ptr::from_ref(used.avail_event().unwrap()).cast::<u8>();
+            let offset = unsafe { avail_event.offset_from(base) } as usize;
+            assert_eq!(offset, Used::avail_event_offset(queue_size));
         }
     }
 }
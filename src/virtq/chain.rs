@@ -0,0 +1,469 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::{fmt, mem};
+
+use super::{Desc, DescF};
+use crate::{le16, le32, le64};
+
+/// Descriptor Chain Error
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum DescChainError {
+    /// A descriptor index is out of bounds of the descriptor table.
+    OutOfBounds {
+        /// The offending descriptor index.
+        index: u16,
+    },
+
+    /// The chain is longer than the descriptor table and thus contains a loop.
+    Loop,
+}
+
+impl fmt::Display for DescChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index } => {
+                write!(f, "descriptor index {index} is out of bounds")
+            }
+            Self::Loop => f.write_str("descriptor chain contains a loop"),
+        }
+    }
+}
+
+impl core::error::Error for DescChainError {}
+
+/// Descriptor Chain Iterator
+///
+/// This iterator starts at a head descriptor and follows the `next` fields
+/// of descriptors with [`DescF::NEXT`] set.
+///
+/// Since a chain can never be longer than the descriptor table, this iterator
+/// yields at most `table.len()` descriptors.
+/// If the chain is malformed, an error is yielded once and the iterator is fused.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::virtq::{Desc, DescChain, DescF};
+/// use virtio::{le16, le32, le64};
+///
+/// let desc = |flags, next| Desc {
+///     addr: le64::from_ne(0),
+///     len: le32::from_ne(0),
+///     flags,
+///     next: le16::from_ne(next),
+/// };
+/// let table = [desc(DescF::empty(), 0), desc(DescF::NEXT, 0)];
+///
+/// let chain = DescChain::new(&table, 1);
+/// assert_eq!(chain.count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DescChain<'a> {
+    table: &'a [Desc],
+    next: Option<u16>,
+    remaining: usize,
+}
+
+impl<'a> DescChain<'a> {
+    /// Creates a new descriptor chain iterator starting at `head`.
+    pub fn new(table: &'a [Desc], head: u16) -> Self {
+        Self {
+            table,
+            next: Some(head),
+            remaining: table.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for DescChain<'a> {
+    type Item = Result<&'a Desc, DescChainError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next.take()?;
+
+        let Some(desc) = self.table.get(usize::from(index)) else {
+            return Some(Err(DescChainError::OutOfBounds { index }));
+        };
+
+        if self.remaining == 0 {
+            return Some(Err(DescChainError::Loop));
+        }
+        self.remaining -= 1;
+
+        if desc.flags.contains(DescF::NEXT) {
+            self.next = Some(desc.next.to_ne());
+        }
+
+        Some(Ok(desc))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => (1, Some(self.remaining + 1)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl FusedIterator for DescChain<'_> {}
+
+/// Descriptor Chain Builder Error
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum DescChainBuilderError {
+    /// There are no free descriptors left.
+    TableFull,
+
+    /// A device-readable buffer was pushed after a device-writable buffer.
+    ReadableAfterWritable,
+}
+
+impl fmt::Display for DescChainBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TableFull => f.write_str("descriptor table is full"),
+            Self::ReadableAfterWritable => {
+                f.write_str("device-readable buffer pushed after device-writable buffer")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DescChainBuilderError {}
+
+/// Descriptor Chain Builder
+///
+/// This builder writes a descriptor chain into free descriptors of a descriptor table.
+/// It links the descriptors via [`DescF::NEXT`] and the `next` field and
+/// marks device-writable buffers with [`DescF::WRITE`].
+///
+/// The caller provides the indices of free descriptors, for example from its free list.
+/// Each pushed buffer takes the next free index, and the first one becomes the head of the chain.
+/// Pushing fails with [`DescChainBuilderError::TableFull`] once there are no free indices left.
+///
+/// Device-readable buffers have to be pushed before device-writable buffers.
+/// Pushing a device-readable buffer after a device-writable buffer fails with [`DescChainBuilderError::ReadableAfterWritable`].
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::virtq::{Desc, DescChainBuilder, DescF};
+/// use virtio::{le16, le32, le64};
+///
+/// let mut table = [Desc {
+///     addr: le64::from_ne(0),
+///     len: le32::from_ne(0),
+///     flags: DescF::empty(),
+///     next: le16::from_ne(0),
+/// }; 4];
+///
+/// let mut builder = DescChainBuilder::new(&mut table, [3, 0]);
+/// builder.push_readable(0x1000, 16).unwrap();
+/// builder.push_writable(0x2000, 1).unwrap();
+/// assert_eq!(builder.finish(), Some(3));
+///
+/// assert_eq!(table[3].flags, DescF::NEXT);
+/// assert_eq!(table[3].next.to_ne(), 0);
+/// assert_eq!(table[0].flags, DescF::WRITE);
+/// ```
+#[derive(Debug)]
+pub struct DescChainBuilder<'a, I> {
+    table: &'a mut [Desc],
+    free: I,
+    head: Option<u16>,
+    tail: u16,
+    len: u16,
+    writable: bool,
+}
+
+impl<'a, I: Iterator<Item = u16>> DescChainBuilder<'a, I> {
+    /// Creates a new descriptor chain builder that takes descriptors from `free`.
+    ///
+    /// `free` yields the indices of free descriptors in `table`.
+    /// The builder only takes as many indices as buffers are pushed.
+    pub fn new(table: &'a mut [Desc], free: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            table,
+            free: free.into_iter(),
+            head: None,
+            tail: 0,
+            len: 0,
+            writable: false,
+        }
+    }
+
+    /// Appends a device-readable buffer to the chain.
+    ///
+    /// Fails if a device-writable buffer has already been pushed or if there are no free descriptors left.
+    pub fn push_readable(
+        &mut self,
+        addr: u64,
+        len: u32,
+    ) -> Result<&mut Self, DescChainBuilderError> {
+        if self.writable {
+            return Err(DescChainBuilderError::ReadableAfterWritable);
+        }
+        self.push(addr, len, DescF::empty())
+    }
+
+    /// Appends a device-writable buffer to the chain.
+    ///
+    /// Fails if there are no free descriptors left.
+    pub fn push_writable(
+        &mut self,
+        addr: u64,
+        len: u32,
+    ) -> Result<&mut Self, DescChainBuilderError> {
+        self.push(addr, len, DescF::WRITE)?;
+        self.writable = true;
+        Ok(self)
+    }
+
+    fn push(
+        &mut self,
+        addr: u64,
+        len: u32,
+        flags: DescF,
+    ) -> Result<&mut Self, DescChainBuilderError> {
+        let index = self.free.next().ok_or(DescChainBuilderError::TableFull)?;
+
+        if self.head.is_some() {
+            let prev = &mut self.table[usize::from(self.tail)];
+            prev.flags.insert(DescF::NEXT);
+            prev.next = le16::from_ne(index);
+        } else {
+            self.head = Some(index);
+        }
+
+        self.table[usize::from(index)] = Desc {
+            addr: le64::from_ne(addr),
+            len: le32::from_ne(len),
+            flags,
+            next: le16::from_ne(0),
+        };
+        self.tail = index;
+        self.len += 1;
+
+        Ok(self)
+    }
+
+    /// Finishes the chain and returns its head index.
+    ///
+    /// Returns `None` if no buffers were pushed.
+    pub fn finish(self) -> Option<u16> {
+        self.head
+    }
+}
+
+impl<'a> DescChainBuilder<'a, Range<u16>> {
+    /// Creates a new descriptor chain builder for an indirect descriptor table.
+    ///
+    /// The chain occupies consecutive descriptors starting at index 0, since the device
+    /// reads the first indirect descriptor from the start of the table.
+    /// Use [`finish_indirect`](Self::finish_indirect) to finish the chain.
+    pub fn new_indirect(table: &'a mut [Desc]) -> Self {
+        let len = table.len().try_into().unwrap_or(u16::MAX);
+        Self::new(table, 0..len)
+    }
+
+    /// Finishes the chain as an indirect descriptor table.
+    ///
+    /// `table_addr` is the guest-physical address of the descriptor table.
+    /// This returns the descriptor with [`DescF::INDIRECT`] that refers to the
+    /// table and can be placed in the virtqueue instead of the chain
+    /// (only if [`VIRTIO_F_INDIRECT_DESC`](crate::F::INDIRECT_DESC) negotiated).
+    ///
+    /// Returns `None` if no buffers were pushed or if the chain does not start at index 0.
+    /// Use [`new_indirect`](Self::new_indirect) to create a builder starting at index 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::virtq::{Desc, DescChainBuilder, DescF};
+    /// use virtio::{le16, le32, le64};
+    ///
+    /// let mut table = [Desc {
+    ///     addr: le64::from_ne(0),
+    ///     len: le32::from_ne(0),
+    ///     flags: DescF::empty(),
+    ///     next: le16::from_ne(0),
+    /// }; 2];
+    ///
+    /// let mut builder = DescChainBuilder::new_indirect(&mut table);
+    /// builder.push_readable(0x1000, 16).unwrap();
+    /// builder.push_writable(0x2000, 1).unwrap();
+    /// let desc = builder.finish_indirect(0x3000).unwrap();
+    ///
+    /// assert_eq!(desc.addr.to_ne(), 0x3000);
+    /// assert_eq!(desc.len.to_ne(), 32);
+    /// assert_eq!(desc.flags, DescF::INDIRECT);
+    /// ```
+    pub fn finish_indirect(self, table_addr: u64) -> Option<Desc> {
+        if self.head != Some(0) {
+            return None;
+        }
+
+        let len = usize::from(self.len) * mem::size_of::<Desc>();
+        Some(Desc {
+            addr: le64::from_ne(table_addr),
+            len: le32::from_ne(len.try_into().unwrap()),
+            flags: DescF::INDIRECT,
+            next: le16::from_ne(0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn desc(flags: DescF, next: u16) -> Desc {
+        Desc {
+            addr: le64::from_ne(0),
+            len: le32::from_ne(next.into()),
+            flags,
+            next: le16::from_ne(next),
+        }
+    }
+
+    #[test]
+    fn chain() {
+        let table = [
+            desc(DescF::NEXT, 2),
+            desc(DescF::empty(), 0),
+            desc(DescF::NEXT | DescF::WRITE, 1),
+        ];
+
+        let lens = DescChain::new(&table, 0)
+            .map(|desc| desc.unwrap().len.to_ne())
+            .collect::<Vec<_>>();
+        assert_eq!(lens, [2, 1, 0]);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let table = [desc(DescF::NEXT, 3)];
+
+        let mut chain = DescChain::new(&table, 0);
+        assert!(chain.next().unwrap().is_ok());
+        assert_eq!(
+            chain.next().unwrap().unwrap_err(),
+            DescChainError::OutOfBounds { index: 3 }
+        );
+        assert!(chain.next().is_none());
+
+        let mut chain = DescChain::new(&table, 1);
+        assert_eq!(
+            chain.next().unwrap().unwrap_err(),
+            DescChainError::OutOfBounds { index: 1 }
+        );
+        assert!(chain.next().is_none());
+    }
+
+    #[test]
+    fn loop_() {
+        let table = [desc(DescF::NEXT, 1), desc(DescF::NEXT, 0)];
+
+        let mut chain = DescChain::new(&table, 0);
+        assert!(chain.next().unwrap().is_ok());
+        assert!(chain.next().unwrap().is_ok());
+        assert_eq!(chain.next().unwrap().unwrap_err(), DescChainError::Loop);
+        assert!(chain.next().is_none());
+    }
+
+    #[test]
+    fn builder_full() {
+        let mut table = [desc(DescF::empty(), 0); 2];
+
+        let mut builder = DescChainBuilder::new(&mut table, 0..2);
+        builder.push_readable(0, 1).unwrap();
+        builder.push_writable(0, 2).unwrap();
+        assert_eq!(
+            builder.push_writable(0, 3).unwrap_err(),
+            DescChainBuilderError::TableFull
+        );
+        assert_eq!(builder.finish(), Some(0));
+
+        let lens = DescChain::new(&table, 0)
+            .map(|desc| desc.unwrap().len.to_ne())
+            .collect::<Vec<_>>();
+        assert_eq!(lens, [1, 2]);
+
+        let builder = DescChainBuilder::new(&mut table, 2..2);
+        assert_eq!(builder.finish(), None);
+    }
+
+    #[test]
+    fn builder_wraparound() {
+        let mut table = [desc(DescF::empty(), 0); 4];
+
+        let mut builder = DescChainBuilder::new(&mut table, (2..4).chain(0..2));
+        builder.push_readable(0, 1).unwrap();
+        builder.push_readable(0, 2).unwrap();
+        builder.push_writable(0, 3).unwrap();
+        assert_eq!(builder.finish(), Some(2));
+
+        let chain = DescChain::new(&table, 2)
+            .map(|desc| {
+                let desc = desc.unwrap();
+                (desc.len.to_ne(), desc.next.to_ne())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(chain, [(1, 3), (2, 0), (3, 0)]);
+        assert_eq!(table[0].flags, DescF::WRITE);
+    }
+
+    #[test]
+    fn builder_readable_after_writable() {
+        let mut table = [desc(DescF::empty(), 0); 2];
+
+        let mut builder = DescChainBuilder::new(&mut table, 0..2);
+        builder.push_writable(0, 1).unwrap();
+        assert_eq!(
+            builder.push_readable(0, 2).unwrap_err(),
+            DescChainBuilderError::ReadableAfterWritable
+        );
+        assert_eq!(builder.finish(), Some(0));
+
+        let lens = DescChain::new(&table, 0)
+            .map(|desc| desc.unwrap().len.to_ne())
+            .collect::<Vec<_>>();
+        assert_eq!(lens, [1]);
+    }
+
+    #[test]
+    fn indirect() {
+        assert_eq!(mem::size_of::<Desc>(), 16);
+
+        let mut table = [desc(DescF::empty(), 0); 3];
+        let mut builder = DescChainBuilder::new_indirect(&mut table);
+        builder.push_readable(0x1000, 0x10).unwrap();
+        builder.push_readable(0x2000, 0x20).unwrap();
+        builder.push_writable(0x3000, 0x30).unwrap();
+        let desc = builder.finish_indirect(0x8000).unwrap();
+
+        assert_eq!(desc.addr.to_ne(), 0x8000);
+        assert_eq!(desc.len.to_ne(), 3 * 16);
+        assert_eq!(desc.flags, DescF::INDIRECT);
+
+        let bytes = unsafe { core::slice::from_raw_parts(table.as_ptr().cast::<u8>(), 3 * 16) };
+        #[rustfmt::skip]
+        let expected: [u8; 3 * 16] = [
+            // addr, len, flags (NEXT), next
+            0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0x01, 0, 0x01, 0,
+            // addr, len, flags (NEXT), next
+            0x00, 0x20, 0, 0, 0, 0, 0, 0, 0x20, 0, 0, 0, 0x01, 0, 0x02, 0,
+            // addr, len, flags (WRITE), next
+            0x00, 0x30, 0, 0, 0, 0, 0, 0, 0x30, 0, 0, 0, 0x02, 0, 0x00, 0,
+        ];
+        assert_eq!(bytes, expected);
+
+        let mut builder = DescChainBuilder::new(&mut table, 1..3);
+        builder.push_readable(0x1000, 0x10).unwrap();
+        assert!(builder.finish_indirect(0x8000).is_none());
+    }
+}
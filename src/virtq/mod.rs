@@ -2,31 +2,223 @@
 
 #[cfg(feature = "alloc")]
 mod alloc;
+mod chain;
+mod ring;
 
 use core::alloc::Layout;
 use core::ptr::{addr_of_mut, NonNull};
-use core::{mem, ptr};
+use core::{fmt, mem, ptr};
 
+#[cfg(feature = "alloc")]
+pub(crate) use self::alloc::desc_table_alloc_impl;
+pub use self::chain::{DescChain, DescChainBuilder, DescChainBuilderError, DescChainError};
+pub use self::ring::{AvailRing, SplitQueue, UsedRing};
 use crate::{le16, le32, le64};
 
+/// Implements `layout` and `from_ptr` for a descriptor table newtype around `[$Desc]`.
+///
+/// This is shared by [`DescTable`] and [`pvirtq::DescRing`](crate::pvirtq::DescRing),
+/// which have the same layout requirements.
+macro_rules! desc_table_impl {
+    ($Table:ident, $Desc:ty) => {
+        impl $Table {
+            /// Returns the layout of a table with `queue_size` descriptors.
+            pub fn layout(queue_size: u16) -> ::core::alloc::Layout {
+                ::core::alloc::Layout::array::<$Desc>(queue_size.into())
+                    .unwrap()
+                    .align_to(16)
+                    .unwrap()
+            }
+
+            /// Creates a table pointer from a byte slice pointer.
+            ///
+            /// Returns `None` if `ptr` is not aligned to 16 bytes or if its length
+            /// is not a multiple of the descriptor size.
+            pub fn from_ptr(ptr: ::core::ptr::NonNull<[u8]>) -> Option<::core::ptr::NonNull<Self>> {
+                let len = ptr.len();
+                let ptr = ptr.cast::<u8>().as_ptr();
+
+                if ptr.align_offset(16) != 0 {
+                    return None;
+                }
+
+                if len % ::core::mem::size_of::<$Desc>() != 0 {
+                    return None;
+                }
+
+                let len = len / ::core::mem::size_of::<$Desc>();
+                let ptr =
+                    ::core::ptr::slice_from_raw_parts_mut(ptr.cast::<$Desc>(), len) as *mut Self;
+                Some(::core::ptr::NonNull::new(ptr).unwrap())
+            }
+        }
+    };
+}
+
+pub(crate) use desc_table_impl;
+
+/// Returns whether `size` is a valid split virtqueue size.
+///
+/// Split virtqueue sizes are nonzero powers of 2.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::virtq;
+///
+/// const _: () = assert!(virtq::is_valid_size(256));
+/// assert!(!virtq::is_valid_size(0));
+/// assert!(!virtq::is_valid_size(96));
+/// ```
+pub const fn is_valid_size(size: u16) -> bool {
+    size.is_power_of_two()
+}
+
+/// Returns whether the other side needs to be notified.
+///
+/// With [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX), the driver (device) has
+/// to notify the device (driver) if the ring index moved past `event_idx`
+/// while being advanced from `old_idx` to `new_idx`.
+/// `event_idx` is `avail_event` for the driver and `used_event` for the device.
+///
+/// All indices are free-running counters that wrap around at [`u16::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::virtq;
+///
+/// assert!(virtq::needs_notify(5, 6, 5));
+/// assert!(!virtq::needs_notify(6, 6, 5));
+/// ```
+#[doc(alias = "vring_need_event")]
+pub const fn needs_notify(event_idx: u16, new_idx: u16, old_idx: u16) -> bool {
+    new_idx.wrapping_sub(event_idx).wrapping_sub(1) < new_idx.wrapping_sub(old_idx)
+}
+
+/// The memory layout of a split virtqueue in a single contiguous region.
+///
+/// See [`layout`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QueueLayout {
+    /// The layout of the whole region.
+    pub layout: Layout,
+
+    /// The byte offset of the [`DescTable`].
+    pub desc_offset: usize,
+
+    /// The byte offset of the [`Avail`] ring.
+    pub avail_offset: usize,
+
+    /// The byte offset of the [`Used`] ring.
+    pub used_offset: usize,
+}
+
+/// Computes the layout of a split virtqueue in a single contiguous region.
+///
+/// The descriptor table, the available ring, and the used ring are placed
+/// one after another, each with its required alignment.
+/// `has_event_idx` determines whether the rings include `used_event` and `avail_event`.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::virtq;
+///
+/// let layout = virtq::layout(256, false);
+/// assert_eq!(layout.desc_offset, 0);
+/// assert_eq!(layout.avail_offset, 4096);
+/// assert_eq!(layout.used_offset, 4612);
+/// assert_eq!(layout.layout.size(), 6672);
+/// assert_eq!(layout.layout.align(), 16);
+/// ```
+pub fn layout(queue_size: u16, has_event_idx: bool) -> QueueLayout {
+    let desc = DescTable::layout(queue_size);
+    let (layout, avail_offset) = desc
+        .extend(Avail::layout(queue_size, has_event_idx))
+        .unwrap();
+    let (layout, used_offset) = layout
+        .extend(Used::layout(queue_size, has_event_idx))
+        .unwrap();
+
+    QueueLayout {
+        layout: layout.pad_to_align(),
+        desc_offset: 0,
+        avail_offset,
+        used_offset,
+    }
+}
+
 /// Split Virtqueue Descriptor
 #[doc(alias = "virtq_desc")]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Desc {
     /// Address (guest-physical).
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub addr: le64,
 
     /// Length.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub len: le32,
 
     /// The flags as indicated in [`DescF`].
     pub flags: DescF,
 
     /// Next field if flags & NEXT
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub next: le16,
 }
 
+defmt_format_impl! {
+    Desc {
+        addr,
+        len,
+        flags,
+        next,
+    }
+}
+
+impl Desc {
+    /// Creates a new descriptor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::virtq::{Desc, DescF};
+    ///
+    /// static TABLE: [Desc; 2] = [
+    ///     Desc::new(0x1000, 0x10, DescF::NEXT, 1),
+    ///     Desc::new(0x2000, 0x20, DescF::WRITE, 0),
+    /// ];
+    ///
+    /// assert_eq!(TABLE[0].addr.to_ne(), 0x1000);
+    /// assert_eq!(TABLE[1].flags, DescF::WRITE);
+    /// ```
+    pub const fn new(addr: u64, len: u32, flags: DescF, next: u16) -> Self {
+        Self {
+            addr: le64::from_ne(addr),
+            len: le32::from_ne(len),
+            flags,
+            next: le16::from_ne(next),
+        }
+    }
+}
+
+/// The Virtqueue Descriptor Table
+///
+/// The descriptor table has to be aligned to 16 bytes.
+#[derive(Debug)]
+#[repr(C, align(16))]
+pub struct DescTable(pub [Desc]);
+
+desc_table_impl!(DescTable, Desc);
+
 endian_bitflags! {
     /// Virtqueue descriptor flags
     #[doc(alias = "VIRTQ_DESC_F")]
@@ -51,6 +243,78 @@ endian_bitflags! {
     }
 }
 
+impl DescF {
+    /// Checks that these flags are a valid combination for a driver to set.
+    ///
+    /// `in_indirect_table` states whether the descriptor is part of an indirect descriptor table.
+    ///
+    /// The following rules from _Basic Facilities of a Virtio Device / Virtqueues_ are checked:
+    ///
+    /// - [`INDIRECT`](Self::INDIRECT) and [`NEXT`](Self::NEXT) must not be set together.
+    /// - `INDIRECT` must not be set within an indirect descriptor table.
+    ///
+    /// Setting [`WRITE`](Self::WRITE) together with `INDIRECT` is allowed, but the device ignores it.
+    /// Whether `INDIRECT` may be set at all depends on [`VIRTIO_F_INDIRECT_DESC`](crate::F::INDIRECT_DESC),
+    /// which is not checked here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use virtio::virtq::{DescF, DescFError};
+    ///
+    /// assert_eq!((DescF::NEXT | DescF::WRITE).validate(false), Ok(()));
+    /// assert_eq!(
+    ///     (DescF::INDIRECT | DescF::NEXT).validate(false),
+    ///     Err(DescFError::IndirectWithNext)
+    /// );
+    /// assert_eq!(DescF::INDIRECT.validate(true), Err(DescFError::NestedIndirect));
+    /// ```
+    pub const fn validate(self, in_indirect_table: bool) -> Result<(), DescFError> {
+        if !self.contains(Self::INDIRECT) {
+            return Ok(());
+        }
+
+        if in_indirect_table {
+            return Err(DescFError::NestedIndirect);
+        }
+
+        if self.contains(Self::NEXT) {
+            return Err(DescFError::IndirectWithNext);
+        }
+
+        Ok(())
+    }
+}
+
+/// Invalid Descriptor Flags Error
+///
+/// See [`DescF::validate`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum DescFError {
+    /// [`INDIRECT`](DescF::INDIRECT) is set together with [`NEXT`](DescF::NEXT).
+    IndirectWithNext,
+
+    /// [`INDIRECT`](DescF::INDIRECT) is set within an indirect descriptor table.
+    NestedIndirect,
+}
+
+impl fmt::Display for DescFError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndirectWithNext => {
+                f.write_str("INDIRECT and NEXT descriptor flags are both set")
+            }
+            Self::NestedIndirect => {
+                f.write_str("INDIRECT descriptor flag is set within an indirect table")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DescFError {}
+
 /// The Virtqueue Available Ring
 #[doc(alias = "virtq_avail")]
 #[derive(Debug)]
@@ -66,6 +330,13 @@ impl Avail {
         Layout::array::<le16>(2 + usize::from(queue_size) + usize::from(has_event_idx)).unwrap()
     }
 
+    /// Returns the byte offset of `used_event` for a queue with `queue_size` entries.
+    ///
+    /// `used_event` is only present if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has been negotiated.
+    pub const fn used_event_offset(queue_size: u16) -> usize {
+        (2 + queue_size as usize) * mem::size_of::<le16>()
+    }
+
     pub fn from_ptr(ptr: NonNull<[u8]>) -> Option<NonNull<Self>> {
         let len = ptr.as_ptr().len();
         // FIXME: use ptr::as_mut_ptr once stable
@@ -177,6 +448,13 @@ impl Used {
             .pad_to_align()
     }
 
+    /// Returns the byte offset of `avail_event` for a queue with `queue_size` entries.
+    ///
+    /// `avail_event` is only present if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has been negotiated.
+    pub const fn avail_event_offset(queue_size: u16) -> usize {
+        2 * mem::size_of::<le16>() + queue_size as usize * mem::size_of::<UsedElem>()
+    }
+
     pub fn from_ptr(ptr: NonNull<[u8]>, has_event_idx: bool) -> Option<NonNull<Self>> {
         let len = ptr.len();
         let ptr = ptr.cast::<u8>().as_ptr();
@@ -249,14 +527,58 @@ endian_bitflags! {
 /// Used Ring Entry
 #[doc(alias = "virtq_used_elem")]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct UsedElem {
     /// Index of start of used descriptor chain.
     ///
     /// [`le32`] is used here for ids for padding reasons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub id: le32,
 
     /// The number of bytes written into the device writable portion of
     /// the buffer described by the descriptor chain.
+    #[cfg_attr(feature = "serde", serde(with = "crate::endian_serde"))]
     pub len: le32,
 }
+
+defmt_format_impl! {
+    UsedElem {
+        id,
+        len,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desc_debug() {
+        use std::format;
+
+        let desc = Desc::new(0x1000, 16, DescF::NEXT | DescF::WRITE, 3);
+        assert_eq!(
+            format!("{desc:?}"),
+            "Desc { addr: 4096, len: 16, flags: DescF(NEXT | WRITE), next: 3 }"
+        );
+    }
+
+    #[test]
+    fn needs_notify_wraparound() {
+        // The event index is within the new entries.
+        assert!(needs_notify(u16::MAX, 1, u16::MAX - 1));
+        assert!(needs_notify(u16::MAX, 0, u16::MAX));
+        assert!(needs_notify(0, 1, u16::MAX));
+
+        // The event index is at the new index and thus has not been passed yet.
+        assert!(!needs_notify(1, 1, u16::MAX));
+        assert!(!needs_notify(0, 0, u16::MAX));
+
+        // The event index has been passed before.
+        assert!(!needs_notify(u16::MAX - 1, 1, u16::MAX));
+
+        // Nothing new.
+        assert!(!needs_notify(u16::MAX, u16::MAX, u16::MAX));
+    }
+}
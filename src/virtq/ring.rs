@@ -0,0 +1,419 @@
+use core::ptr::{addr_of_mut, NonNull};
+use core::sync::atomic::{self, Ordering};
+
+use volatile::VolatilePtr;
+
+use super::{Avail, DescTable, Used, UsedElem};
+use crate::le16;
+
+/// Split Virtqueue Memory View
+///
+/// This splits a caller-provided buffer into volatile pointers to the
+/// descriptor table, the available ring, and the used ring as computed by
+/// [`layout`](super::layout), without allocating.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use virtio::virtq::{self, AvailRing, SplitQueue, UsedRing};
+///
+/// #[repr(align(16))]
+/// struct Mem([u8; 128]);
+///
+/// let mut mem = Mem([0; 128]);
+/// assert!(virtq::layout(4, true).layout.size() <= mem.0.len());
+///
+/// let queue = SplitQueue::from_buf(&mut mem.0, 4, true).unwrap();
+/// assert_eq!(unsafe { queue.desc.as_raw_ptr().as_ref() }.0.len(), 4);
+///
+/// let avail = AvailRing::new(queue.avail, true);
+/// avail.push(0);
+/// assert_eq!(avail.idx(), 1);
+///
+/// let used = UsedRing::new(queue.used);
+/// assert_eq!(used.idx(), 0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SplitQueue<'a> {
+    pub desc: VolatilePtr<'a, DescTable>,
+    pub avail: VolatilePtr<'a, Avail>,
+    pub used: VolatilePtr<'a, Used>,
+}
+
+impl<'a> SplitQueue<'a> {
+    /// Splits `buf` into the parts of a split virtqueue with `queue_size` entries.
+    ///
+    /// `has_event_idx` has to be true if and only if
+    /// [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has been negotiated.
+    ///
+    /// Returns `None` if `buf` is not aligned to 16 bytes or is smaller than the
+    /// size from [`layout`](super::layout).
+    /// The buffer is not zeroed.
+    pub fn from_buf(buf: &'a mut [u8], queue_size: u16, has_event_idx: bool) -> Option<Self> {
+        let layout = super::layout(queue_size, has_event_idx);
+        if buf.len() < layout.layout.size() {
+            return None;
+        }
+
+        let base = NonNull::from(buf).cast::<u8>();
+        let region = |offset: usize, len: usize| {
+            let ptr = unsafe { base.add(offset) };
+            NonNull::slice_from_raw_parts(ptr, len)
+        };
+
+        let desc_len = DescTable::layout(queue_size).size();
+        let avail_len = Avail::layout(queue_size, has_event_idx).size();
+        let used_len = Used::layout(queue_size, has_event_idx).size();
+
+        let desc = DescTable::from_ptr(region(layout.desc_offset, desc_len))?;
+        let avail = Avail::from_ptr(region(layout.avail_offset, avail_len))?;
+        let used = Used::from_ptr(region(layout.used_offset, used_len), has_event_idx)?;
+
+        // SAFETY: The regions are disjoint and borrowed mutably for `'a`.
+        unsafe {
+            Some(Self {
+                desc: VolatilePtr::new(desc),
+                avail: VolatilePtr::new(avail),
+                used: VolatilePtr::new(used),
+            })
+        }
+    }
+}
+
+/// Available Ring Driver Handle
+///
+/// This wraps a volatile pointer to an [`Avail`] ring and encodes the memory
+/// ordering that the driver has to observe when making buffers available to the device.
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use core::ptr::NonNull;
+///
+/// use virtio::virtq::{Avail, AvailRing};
+/// use volatile::VolatilePtr;
+///
+/// let mut mem = [0u16; 2 + 4 + 1];
+/// let ptr = NonNull::from(mem.as_mut_slice()).cast::<u8>();
+/// let ptr = NonNull::slice_from_raw_parts(ptr, 2 * mem.len());
+/// let avail = Avail::from_ptr(ptr).unwrap();
+///
+/// let ring = AvailRing::new(unsafe { VolatilePtr::new(avail) }, true);
+/// ring.push(3);
+/// assert_eq!(ring.idx(), 1);
+/// assert_eq!(ring.used_event(), Some(0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AvailRing<'a> {
+    ptr: VolatilePtr<'a, Avail>,
+    has_event_idx: bool,
+}
+
+impl<'a> AvailRing<'a> {
+    /// Creates a new available ring handle.
+    ///
+    /// `has_event_idx` has to be true if and only if
+    /// [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has been negotiated.
+    pub fn new(ptr: VolatilePtr<'a, Avail>, has_event_idx: bool) -> Self {
+        Self { ptr, has_event_idx }
+    }
+
+    fn idx_ptr(self) -> VolatilePtr<'a, le16> {
+        unsafe {
+            self.ptr
+                .map(|ptr| NonNull::new_unchecked(addr_of_mut!((*ptr.as_ptr()).idx)))
+        }
+    }
+
+    /// Returns the index at which the driver puts the next descriptor head.
+    ///
+    /// This is a free-running counter that wraps around at [`u16::MAX`].
+    pub fn idx(&self) -> u16 {
+        self.idx_ptr().read().to_ne()
+    }
+
+    /// Makes the descriptor chain starting at `head` available to the device.
+    ///
+    /// This writes `head` into the next ring entry and only then increments
+    /// `idx` so that the device never observes an index that covers an
+    /// entry that has not been written yet.
+    ///
+    /// The caller still has to notify the device afterwards, if necessary.
+    pub fn push(&self, head: u16) {
+        let idx = self.idx();
+        let has_event_idx = self.has_event_idx;
+
+        let ring = unsafe { self.ptr.map(|ptr| Avail::ring_ptr(ptr, has_event_idx)) };
+        let pos = usize::from(idx) % ring.as_raw_ptr().len();
+        let entry = unsafe { ring.map(|ring| ring.cast::<le16>().add(pos)) };
+        entry.write(le16::from_ne(head));
+
+        // The ring entry must be visible before the index update.
+        atomic::fence(Ordering::Release);
+
+        self.idx_ptr().write(le16::from_ne(idx.wrapping_add(1)));
+    }
+
+    /// Reads `used_event`, the used ring index after which the driver
+    /// wants to be notified.
+    ///
+    /// Returns `None` if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has not been negotiated.
+    pub fn used_event(&self) -> Option<u16> {
+        let has_event_idx = self.has_event_idx;
+        Avail::used_event_ptr(self.ptr.as_raw_ptr(), has_event_idx)?;
+        let used_event = unsafe {
+            self.ptr
+                .map(|ptr| Avail::used_event_ptr(ptr, has_event_idx).unwrap())
+        };
+        Some(used_event.read().to_ne())
+    }
+
+    /// Writes `used_event`, asking the device to notify the driver only once
+    /// it has written the used ring entry at index `used_event`.
+    ///
+    /// Since the device might have used more buffers before observing the
+    /// new value, the driver should check the used ring again afterwards.
+    ///
+    /// Does nothing if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has not been negotiated.
+    pub fn set_used_event(&self, used_event: u16) {
+        let has_event_idx = self.has_event_idx;
+        if Avail::used_event_ptr(self.ptr.as_raw_ptr(), has_event_idx).is_none() {
+            return;
+        }
+        let ptr = unsafe {
+            self.ptr
+                .map(|ptr| Avail::used_event_ptr(ptr, has_event_idx).unwrap())
+        };
+        ptr.write(le16::from_ne(used_event));
+    }
+}
+
+/// Used Ring Driver Handle
+///
+/// This wraps a volatile pointer to a [`Used`] ring and encodes the memory
+/// ordering that the driver has to observe when reading used buffers from the device.
+///
+/// The driver keeps track of the used ring index up to which it has
+/// processed entries and passes it to [`UsedRing::pop`].
+///
+/// # Examples
+///
+/// ```
+/// # use virtio_spec as virtio;
+/// use core::ptr::NonNull;
+///
+/// use virtio::virtq::{Used, UsedRing};
+/// use volatile::VolatilePtr;
+///
+/// let mut mem = [0u32; 1 + 2 * 4 + 1];
+/// // The device has used the descriptor chain with head 3 with 42 bytes written.
+/// mem[0] = u32::from_le_bytes([0, 0, 1, 0]);
+/// mem[1] = 3u32.to_le();
+/// mem[2] = 42u32.to_le();
+///
+/// let ptr = NonNull::from(mem.as_mut_slice()).cast::<u8>();
+/// let ptr = NonNull::slice_from_raw_parts(ptr, 4 * mem.len());
+/// let used = Used::from_ptr(ptr, true).unwrap();
+/// let ring = UsedRing::new(unsafe { VolatilePtr::new(used) });
+///
+/// let mut last_used = 0;
+/// let elem = ring.pop(&mut last_used).unwrap();
+/// assert_eq!(elem.id.to_ne(), 3);
+/// assert_eq!(elem.len.to_ne(), 42);
+/// assert_eq!(last_used, 1);
+/// assert!(ring.pop(&mut last_used).is_none());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UsedRing<'a> {
+    ptr: VolatilePtr<'a, Used>,
+}
+
+impl<'a> UsedRing<'a> {
+    /// Creates a new used ring handle.
+    pub fn new(ptr: VolatilePtr<'a, Used>) -> Self {
+        Self { ptr }
+    }
+
+    /// Returns the index at which the device puts the next used element.
+    ///
+    /// This is a free-running counter that wraps around at [`u16::MAX`].
+    pub fn idx(&self) -> u16 {
+        let ptr = unsafe {
+            self.ptr
+                .map(|ptr| NonNull::new_unchecked(addr_of_mut!((*ptr.as_ptr()).idx)))
+        };
+        ptr.read().to_ne()
+    }
+
+    /// Returns the next used element after `last_used` and advances `last_used`.
+    ///
+    /// `last_used` is the index of the next used ring entry that the driver has not processed yet.
+    /// Returns `None` if the device has not used any further buffers.
+    pub fn pop(&self, last_used: &mut u16) -> Option<UsedElem> {
+        let elem = self.peek(*last_used)?;
+        *last_used = last_used.wrapping_add(1);
+        Some(elem)
+    }
+
+    /// Returns the next used element after `last_used` for a whole batch of buffers and advances `last_used` past the batch.
+    ///
+    /// Only use this if [`VIRTIO_F_IN_ORDER`](crate::F::IN_ORDER) has been negotiated.
+    /// Then, the device may use a batch of buffers by writing only a single used element
+    /// for the last buffer of the batch and incrementing the used index by the size of the batch.
+    ///
+    /// `batch_len` returns the size of the batch for the used element.
+    /// Since buffers are used in the order they were made available, this is the number
+    /// of outstanding buffers up to and including the buffer with the head [`UsedElem::id`].
+    /// All of these buffers have been used by the device.
+    ///
+    /// Returns `None` if the device has not used any further buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use virtio_spec as virtio;
+    /// use core::ptr::NonNull;
+    ///
+    /// use virtio::virtq::{Used, UsedRing};
+    /// use volatile::VolatilePtr;
+    ///
+    /// let mut mem = [0u32; 1 + 2 * 4 + 1];
+    /// // The device has used a batch of three buffers with the heads 0, 1, and 2.
+    /// mem[0] = u32::from_le_bytes([0, 0, 3, 0]);
+    /// mem[1] = 2u32.to_le();
+    ///
+    /// let ptr = NonNull::from(mem.as_mut_slice()).cast::<u8>();
+    /// let ptr = NonNull::slice_from_raw_parts(ptr, 4 * mem.len());
+    /// let used = Used::from_ptr(ptr, true).unwrap();
+    /// let ring = UsedRing::new(unsafe { VolatilePtr::new(used) });
+    ///
+    /// // The driver made buffers 0, 1, and 2 available in that order.
+    /// let queue_size = 4;
+    /// let oldest_head = 0;
+    /// let mut last_used = 0;
+    /// let elem = ring
+    ///     .pop_in_order(&mut last_used, |elem| {
+    ///         (elem.id.to_ne() as u16).wrapping_sub(oldest_head) % queue_size + 1
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(elem.id.to_ne(), 2);
+    /// assert_eq!(last_used, 3);
+    /// assert!(ring.pop_in_order(&mut last_used, |_| 1).is_none());
+    /// ```
+    pub fn pop_in_order(
+        &self,
+        last_used: &mut u16,
+        batch_len: impl FnOnce(&UsedElem) -> u16,
+    ) -> Option<UsedElem> {
+        let elem = self.peek(*last_used)?;
+        *last_used = last_used.wrapping_add(batch_len(&elem));
+        Some(elem)
+    }
+
+    fn peek(&self, last_used: u16) -> Option<UsedElem> {
+        if self.idx() == last_used {
+            return None;
+        }
+
+        // The ring entry must not be read before the index update.
+        atomic::fence(Ordering::Acquire);
+
+        let ring = unsafe { self.ptr.map(Used::ring_ptr) };
+        let pos = usize::from(last_used) % ring.as_raw_ptr().len();
+        let entry = unsafe { ring.map(|ring| ring.cast::<UsedElem>().add(pos)) };
+        Some(entry.read())
+    }
+
+    /// Returns an iterator over the used elements after `last_used`, advancing `last_used`.
+    ///
+    /// See [`UsedRing::pop`].
+    pub fn drain<'c>(&self, last_used: &'c mut u16) -> impl Iterator<Item = UsedElem> + 'c
+    where
+        'a: 'c,
+    {
+        let this = *self;
+        core::iter::from_fn(move || this.pop(last_used))
+    }
+
+    /// Reads `avail_event`, the available ring index after which the device
+    /// wants to be notified.
+    ///
+    /// Returns `None` if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has not been negotiated.
+    pub fn avail_event(&self) -> Option<u16> {
+        Used::avail_event_ptr(self.ptr.as_raw_ptr())?;
+        let ptr = unsafe { self.ptr.map(|ptr| Used::avail_event_ptr(ptr).unwrap()) };
+        Some(ptr.read().to_ne())
+    }
+
+    /// Writes `avail_event`, asking the driver to notify the device only once
+    /// it has written the available ring entry at index `avail_event`.
+    ///
+    /// This is written by the device.
+    /// Does nothing if [`VIRTIO_F_EVENT_IDX`](crate::F::EVENT_IDX) has not been negotiated.
+    pub fn set_avail_event(&self, avail_event: u16) {
+        if Used::avail_event_ptr(self.ptr.as_raw_ptr()).is_none() {
+            return;
+        }
+        let ptr = unsafe { self.ptr.map(|ptr| Used::avail_event_ptr(ptr).unwrap()) };
+        ptr.write(le16::from_ne(avail_event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn used_wraparound() {
+        let mut mem = [0u32; 1 + 2 * 4];
+        // idx = 1
+        mem[0] = u32::from_le_bytes([0, 0, 1, 0]);
+        for (i, id) in [(3, 10), (0, 11)] {
+            mem[1 + 2 * i] = u32::to_le(id);
+        }
+
+        let ptr = NonNull::from(mem.as_mut_slice()).cast::<u8>();
+        let ptr = NonNull::slice_from_raw_parts(ptr, 4 * mem.len());
+        let used = Used::from_ptr(ptr, false).unwrap();
+        let ring = UsedRing::new(unsafe { VolatilePtr::new(used) });
+
+        let mut last_used = u16::MAX;
+        let ids = ring
+            .drain(&mut last_used)
+            .map(|elem| elem.id.to_ne())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [10, 11]);
+        assert_eq!(last_used, 1);
+        assert_eq!(ring.avail_event(), None);
+    }
+
+    #[test]
+    fn pop_in_order_wraparound() {
+        let queue_size = 4;
+        let mut mem = [0u32; 1 + 2 * 4];
+        // idx = 9
+        mem[0] = u32::from_le_bytes([0, 0, 9, 0]);
+        // The device has used a batch of the heads 2, 3, and 0 by writing only head 0.
+        mem[1 + 2 * 2] = u32::to_le(0);
+        mem[1 + 2 * 2 + 1] = u32::to_le(1);
+
+        let ptr = NonNull::from(mem.as_mut_slice()).cast::<u8>();
+        let ptr = NonNull::slice_from_raw_parts(ptr, 4 * mem.len());
+        let used = Used::from_ptr(ptr, false).unwrap();
+        let ring = UsedRing::new(unsafe { VolatilePtr::new(used) });
+
+        let oldest_head = 2;
+        let mut last_used = 6;
+        let elem = ring
+            .pop_in_order(&mut last_used, |elem| {
+                (elem.id.to_ne() as u16).wrapping_sub(oldest_head) % queue_size + 1
+            })
+            .unwrap();
+        assert_eq!(elem.id.to_ne(), 0);
+        assert_eq!(elem.len.to_ne(), 1);
+        assert_eq!(last_used, 9);
+        assert!(ring.pop_in_order(&mut last_used, |_| 1).is_none());
+    }
+}
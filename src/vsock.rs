@@ -22,9 +22,16 @@ use crate::le64;
 #[derive(VolatileFieldAccess)]
 #[repr(C)]
 pub struct Config {
+    /// The guest's context ID, which uniquely identifies the device for its lifetime.
     guest_cid: le64,
 }
 
+defmt_format_impl! {
+    Config {
+        guest_cid,
+    }
+}
+
 /// Socket Device Header
 #[doc(alias = "virtio_vsock_hdr")]
 #[cfg_attr(
@@ -39,18 +46,71 @@ pub struct Config {
 #[derive(Default, Clone, Copy, Debug)]
 #[repr(C, packed)]
 pub struct Hdr {
+    /// Source context ID.
     pub src_cid: le64,
+
+    /// Destination context ID.
     pub dst_cid: le64,
+
+    /// Source port.
     pub src_port: le32,
+
+    /// Destination port.
     pub dst_port: le32,
+
+    /// Length of the payload following the header.
     pub len: le32,
+
+    /// Socket type. See [`Type`].
     pub type_: le16,
+
+    /// Operation. See [`Op`].
     pub op: le16,
+
+    /// Operation-specific flags.
+    ///
+    /// See [`ShutdownF`] for [`VIRTIO_VSOCK_OP_SHUTDOWN`](Op::Shutdown) and
+    /// [`Seq`] for [`VIRTIO_VSOCK_OP_RW`](Op::Rw) on [`VIRTIO_VSOCK_TYPE_SEQPACKET`](Type::Seqpacket) sockets.
     pub flags: le32,
+
+    /// Total receive buffer space in bytes for this socket.
     pub buf_alloc: le32,
+
+    /// Free-running bytes received counter.
     pub fwd_cnt: le32,
 }
 
+defmt_format_impl! {
+    Hdr {
+        src_cid,
+        dst_cid,
+        src_port,
+        dst_port,
+        len,
+        type_,
+        op,
+        flags,
+        buf_alloc,
+        fwd_cnt,
+    }
+}
+
+le_bytes_impl! {
+    Hdr {
+        src_cid: le64,
+        dst_cid: le64,
+        src_port: le32,
+        dst_port: le32,
+        len: le32,
+        type_: le16,
+        op: le16,
+        flags: le32,
+        buf_alloc: le32,
+        fwd_cnt: le32,
+    }
+}
+
+/// Socket Device Operation
 #[doc(alias = "VIRTIO_VSOCK_OP")]
 #[derive(
     IntoPrimitive, TryFromPrimitive, UnsafeFromPrimitive, PartialEq, Eq, Clone, Copy, Debug,
@@ -83,6 +143,7 @@ pub enum Op {
     CreditRequest = 7,
 }
 
+/// Socket Type
 #[doc(alias = "VIRTIO_VSOCK_TYPE")]
 #[derive(
     IntoPrimitive, TryFromPrimitive, UnsafeFromPrimitive, PartialEq, Eq, Clone, Copy, Debug,
@@ -111,16 +172,21 @@ endian_bitflags! {
 
 endian_bitflags! {
     /// Socket Device Sequence Flags
+    ///
+    /// Only if [`VIRTIO_VSOCK_F_SEQPACKET`](F::SEQPACKET) negotiated.
     #[doc(alias = "VIRTIO_VSOCK_SEQ")]
     pub struct Seq: le32 {
+        /// This packet is the last packet of a message.
         #[doc(alias = "VIRTIO_VSOCK_SEQ_EOM")]
         const EOM = 1 << 0;
 
+        /// This packet is the last packet of a record.
         #[doc(alias = "VIRTIO_VSOCK_SEQ_EOR")]
         const EOR = 1 << 1;
     }
 }
 
+/// Socket Device Event ID
 #[doc(alias = "VIRTIO_VSOCK_EVENT")]
 #[derive(
     IntoPrimitive, TryFromPrimitive, UnsafeFromPrimitive, PartialEq, Eq, Clone, Copy, Debug,
@@ -128,6 +194,8 @@ endian_bitflags! {
 #[non_exhaustive]
 #[repr(u32)]
 pub enum EventId {
+    /// The communication has been interrupted, for example after live migration.
+    /// The driver has to reset all connections and fetch the new [`guest_cid`](ConfigVolatileFieldAccess::guest_cid).
     #[doc(alias = "VIRTIO_VSOCK_EVENT_TRANSPORT_RESET")]
     TransportReset = 0,
 }
@@ -146,5 +214,6 @@ pub enum EventId {
 #[derive(Default, Clone, Copy, Debug)]
 #[repr(C)]
 pub struct Event {
-    id: le32,
+    /// See [`EventId`].
+    pub id: le32,
 }